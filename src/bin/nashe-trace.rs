@@ -0,0 +1,55 @@
+use std::env;
+use std::fs;
+use std::process;
+use nashe::decode;
+
+// Usage: nashe-trace [--json] <trace files...>
+
+fn main() {
+    let mut json = false;
+    let mut paths: Vec<String> = Vec::new();
+    for arg in env::args().skip(1) {
+        if arg == "--json" {
+            json = true;
+        }
+        else {
+            paths.push(arg);
+        }
+    }
+
+    if paths.is_empty() {
+        eprintln!("Usage: nashe-trace [--json] <trace files...>");
+        process::exit(1);
+    }
+
+    for path in paths.iter() {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("Unable to read '{}': {}", path, err);
+                process::exit(1);
+            }
+        };
+
+        let trace = match decode::parse_trace(&data) {
+            Ok(trace) => trace,
+            Err(err) => {
+                eprintln!("Unable to decode '{}': {}", path, err);
+                process::exit(1);
+            }
+        };
+        if trace.truncated_size > 0 {
+            eprintln!("Warning: '{}' ends with an incomplete record, ignoring its last {:#X} bytes", path, trace.truncated_size);
+        }
+
+        for record in trace.records.iter() {
+            let decoded = decode::decode_record(record);
+            if json {
+                println!("{}", decode::render_json(&decoded));
+            }
+            else {
+                print!("{}", decode::render_text(&decoded));
+            }
+        }
+    }
+}
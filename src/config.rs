@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use nx::result::*;
+use nashe::results::{ResultDescription, ResultDisplay};
+use nashe::parse::{parse_bool, parse_id, parse_list, parse_u32, parse_usize};
+use nx::fs;
+use nx::diag::log;
+use crate::logger;
+use crate::ns::{ApplicationId, GetterServiceKind, ProgramId, ALL_GETTER_SERVICE_KINDS};
+use nashe::filter::{ProgramFilter, ProgramIdRule};
+use crate::order::OrderPolicy;
+use crate::trace;
+
+pub const CONFIG_PATH: &str = "sdmc:/config/nashe/config.ini";
+
+pub const DEFAULT_HOMEBREW_DIRECTORY: &str = "sdmc:/switch";
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Config {
+    pub mitm_services: Vec<GetterServiceKind>,
+    pub log_level: log::LogSeverity,
+    pub homebrew_dirs: Vec<String>,
+    pub host_app_id: Option<ApplicationId>,
+    pub rescan_interval_secs: u32,
+    pub hidden_apps: Vec<ProgramIdRule>,
+    pub order_policy: OrderPolicy,
+    pub pinned_apps: Vec<ApplicationId>,
+    pub mitm_filter: ProgramFilter,
+    pub trace_enabled: bool,
+    pub trace_max_file_size: usize,
+    pub trace_max_files: u32
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            mitm_services: ALL_GETTER_SERVICE_KINDS.to_vec(),
+            log_level: log::LogSeverity::Info,
+            homebrew_dirs: vec![String::from(DEFAULT_HOMEBREW_DIRECTORY)],
+            host_app_id: None,
+            rescan_interval_secs: 5,
+            hidden_apps: Vec::new(),
+            order_policy: OrderPolicy::default(),
+            pinned_apps: Vec::new(),
+            mitm_filter: ProgramFilter::default(),
+            trace_enabled: false,
+            trace_max_file_size: 0x100000,
+            trace_max_files: 4
+        }
+    }
+}
+
+pub const fn get_log_severity_level(severity: log::LogSeverity) -> u32 {
+    match severity {
+        log::LogSeverity::Trace => 0,
+        log::LogSeverity::Info => 1,
+        log::LogSeverity::Warn => 2,
+        log::LogSeverity::Error => 3,
+        log::LogSeverity::Fatal => 4
+    }
+}
+
+fn parse_getter_service_kind(value: &str) -> Option<GetterServiceKind> {
+    match value.to_ascii_lowercase().as_str() {
+        "am2" | "ns:am2" => Some(GetterServiceKind::AM2),
+        "ec" | "ns:ec" => Some(GetterServiceKind::EC),
+        "rid" | "ns:rid" => Some(GetterServiceKind::RID),
+        "rt" | "ns:rt" => Some(GetterServiceKind::RT),
+        "web" | "ns:web" => Some(GetterServiceKind::WEB),
+        "ro" | "ns:ro" => Some(GetterServiceKind::RO),
+        _ => None
+    }
+}
+
+fn parse_log_severity(value: &str) -> Option<log::LogSeverity> {
+    match value.to_ascii_lowercase().as_str() {
+        "trace" => Some(log::LogSeverity::Trace),
+        "info" => Some(log::LogSeverity::Info),
+        "warn" => Some(log::LogSeverity::Warn),
+        "error" => Some(log::LogSeverity::Error),
+        "fatal" => Some(log::LogSeverity::Fatal),
+        _ => None
+    }
+}
+
+impl Config {
+    fn set_value(&mut self, section: &str, key: &str, value: &str) -> bool {
+        match (section, key) {
+            ("mitm", "services") => parse_list(value, parse_getter_service_kind).map(|mitm_services| self.mitm_services = mitm_services).is_some(),
+            ("mitm", "include") => parse_list(value, ProgramIdRule::parse).map(|include| self.mitm_filter.include = include).is_some(),
+            ("mitm", "exclude") => parse_list(value, ProgramIdRule::parse).map(|exclude| self.mitm_filter.exclude = exclude).is_some(),
+            ("log", "level") => parse_log_severity(value).map(|log_level| self.log_level = log_level).is_some(),
+            ("hb", "directories") => parse_list(value, |item| Some(String::from(item.trim_end_matches('/')))).map(|homebrew_dirs| self.homebrew_dirs = homebrew_dirs).is_some(),
+            ("hb", "rescan_interval") => parse_u32(value).map(|interval_secs| self.rescan_interval_secs = interval_secs).is_some(),
+            ("hb", "host_application") => parse_id(value).map(|host_app_id| self.host_app_id = Some(ApplicationId(host_app_id))).is_some(),
+            ("hide", "applications") => parse_list(value, ProgramIdRule::parse).map(|hidden_apps| self.hidden_apps = hidden_apps).is_some(),
+            ("order", "policy") => OrderPolicy::parse(value).map(|order_policy| self.order_policy = order_policy).is_some(),
+            ("order", "pinned") => parse_list(value, |item| parse_id(item).map(ApplicationId)).map(|pinned_apps| self.pinned_apps = pinned_apps).is_some(),
+            ("trace", "enabled") => parse_bool(value).map(|trace_enabled| self.trace_enabled = trace_enabled).is_some(),
+            ("trace", "max_file_size") => parse_usize(value).map(|size| self.trace_max_file_size = core::cmp::max(size, trace::MIN_MAX_FILE_SIZE)).is_some(),
+            ("trace", "max_files") => parse_u32(value).filter(|count| *count > 0).map(|count| self.trace_max_files = count).is_some(),
+            _ => false
+        }
+    }
+
+    pub fn is_application_hidden(&self, app_id: ApplicationId) -> bool {
+        self.hidden_apps.iter().any(|rule| rule.matches(ProgramId(app_id.0)))
+    }
+
+    pub fn parse(data: &str) -> Self {
+        let mut config = Self::default();
+        let mut section = String::new();
+        for (line_idx, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(section_name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                section = section_name.trim().to_ascii_lowercase();
+                continue;
+            }
+
+            let is_valid = match line.split_once('=') {
+                Some((key, value)) => config.set_value(&section, &key.trim().to_ascii_lowercase(), value.trim()),
+                None => false
+            };
+            if !is_valid {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Ignoring invalid config line {}: '{}'\n", line_idx + 1, line);
+            }
+        }
+
+        config
+    }
+}
+
+static mut G_CONFIG: Option<Config> = None;
+
+fn read_config() -> Result<Config> {
+    let mut file = fs::open_file(String::from(CONFIG_PATH), fs::FileOpenOption::Read())?;
+    let mut data: Vec<u8> = vec![0; file.get_size()?];
+    file.read_array(&mut data)?;
+
+    let data_str = core::str::from_utf8(&data).map_err(|_| ResultCode::new(ResultDescription::ConfigParseError.get_value()))?;
+    Ok(Config::parse(data_str))
+}
+
+pub fn load() {
+    let config = match read_config() {
+        Ok(config) => config,
+        Err(rc) => {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to read config, using defaults: {}\n", ResultDisplay(rc.get_value()));
+            Config::default()
+        }
+    };
+
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Config: {:?}\n", config);
+    unsafe {
+        G_CONFIG = Some(config);
+    }
+}
+
+#[inline]
+pub fn get() -> &'static Config {
+    unsafe {
+        G_CONFIG.get_or_insert_with(Config::default)
+    }
+}
@@ -0,0 +1,490 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::vec::Vec;
+use std::string::String;
+use crate::trace_format::{TRACE_MAGIC, TRACE_VERSION, TRACE_SERVICE_NAMES, TraceInterface, TraceParamKind, TraceFileHeader, TraceRecordHeader, TraceParamHeader};
+use crate::results::ResultDisplay;
+use crate::types::{ApplicationId, ApplicationLaunchInfo, ApplicationRecord, ApplicationRightsOnClient, ApplicationView, ProgramId, StorageId, Uid};
+
+use commands::CommandInfo;
+
+// Switch system tick frequency (19.2MHz)
+pub const SYSTEM_TICK_FREQUENCY: u64 = 19200000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DecodeError {
+    InvalidMagic(u32),
+    UnsupportedVersion(u32),
+    InvalidParamKind(u8),
+    Truncated(usize)
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DecodeError::InvalidMagic(magic) => write!(f, "invalid trace magic {:#010X}", magic),
+            DecodeError::UnsupportedVersion(version) => write!(f, "unsupported trace version {}", version),
+            DecodeError::InvalidParamKind(kind) => write!(f, "invalid parameter kind {}", kind),
+            DecodeError::Truncated(offset) => write!(f, "trace data truncated at offset {:#X}", offset)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraceParam {
+    pub kind: TraceParamKind,
+    pub size: u32,
+    pub data: Vec<u8>
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TraceRecord {
+    pub header: TraceRecordHeader,
+    pub params: Vec<TraceParam>
+}
+
+// A trace cut short by a crash or power-off mid-write still has every record before the cut
+// Whatever is left of the last, incomplete record is just reported as its size
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Trace {
+    pub records: Vec<TraceRecord>,
+    pub truncated_size: usize
+}
+
+// Only meant for the plain-data types below, which are valid for any byte pattern
+
+fn read_pod<T: Copy>(data: &[u8]) -> Option<T> {
+    if data.len() < std::mem::size_of::<T>() {
+        return None;
+    }
+    Some(unsafe { std::ptr::read_unaligned(data.as_ptr() as *const T) })
+}
+
+fn read_pod_array<T: Copy>(data: &[u8]) -> Vec<T> {
+    data.chunks_exact(std::mem::size_of::<T>()).filter_map(read_pod::<T>).collect()
+}
+
+fn read_launch_info(data: &[u8]) -> Option<ApplicationLaunchInfo> {
+    // The storage IDs are enums, so they have to be valid before reading the struct itself
+    let app_storage_offset = std::mem::size_of::<ApplicationId>() + 2 * std::mem::size_of::<u32>();
+    StorageId::from_raw(*data.get(app_storage_offset)?)?;
+    StorageId::from_raw(*data.get(app_storage_offset + 1)?)?;
+    read_pod::<ApplicationLaunchInfo>(data)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize
+}
+
+impl<'a> Reader<'a> {
+    fn read_bytes(&mut self, size: usize) -> Result<&'a [u8], DecodeError> {
+        let end = self.offset.checked_add(size).filter(|end| *end <= self.data.len()).ok_or(DecodeError::Truncated(self.offset))?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read<T: Copy>(&mut self) -> Result<T, DecodeError> {
+        let offset = self.offset;
+        let bytes = self.read_bytes(std::mem::size_of::<T>())?;
+        read_pod(bytes).ok_or(DecodeError::Truncated(offset))
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.offset >= self.data.len()
+    }
+}
+
+fn parse_params(data: &[u8]) -> Result<Vec<TraceParam>, DecodeError> {
+    let mut reader = Reader { data, offset: 0 };
+    let mut params: Vec<TraceParam> = Vec::new();
+    while !reader.is_at_end() {
+        let param_header: TraceParamHeader = reader.read()?;
+        let kind = TraceParamKind::from_raw(param_header.kind).ok_or(DecodeError::InvalidParamKind(param_header.kind))?;
+        let param_data = reader.read_bytes(param_header.data_size as usize)?;
+        params.push(TraceParam { kind, size: param_header.size, data: param_data.to_vec() });
+    }
+    Ok(params)
+}
+
+pub fn parse_trace(data: &[u8]) -> Result<Trace, DecodeError> {
+    let mut reader = Reader { data, offset: 0 };
+    let file_header: TraceFileHeader = reader.read()?;
+    if file_header.magic != TRACE_MAGIC {
+        return Err(DecodeError::InvalidMagic(file_header.magic));
+    }
+    if file_header.version != TRACE_VERSION {
+        return Err(DecodeError::UnsupportedVersion(file_header.version));
+    }
+
+    let mut records: Vec<TraceRecord> = Vec::new();
+    while !reader.is_at_end() {
+        let record_offset = reader.offset;
+        let truncated = Trace { records: Vec::new(), truncated_size: data.len() - record_offset };
+        let header: TraceRecordHeader = match reader.read() {
+            Ok(header) => header,
+            Err(_) => return Ok(Trace { records, ..truncated })
+        };
+        let param_data = match reader.read_bytes(header.param_data_size as usize) {
+            Ok(param_data) => param_data,
+            Err(_) => return Ok(Trace { records, ..truncated })
+        };
+
+        let params_offset = record_offset + std::mem::size_of::<TraceRecordHeader>();
+        let params = parse_params(param_data).map_err(|err| match err {
+            DecodeError::Truncated(offset) => DecodeError::Truncated(params_offset + offset),
+            other => other
+        })?;
+        records.push(TraceRecord { header, params });
+    }
+    Ok(Trace { records, truncated_size: 0 })
+}
+
+pub fn format_result(rc: u32) -> String {
+    if rc == 0 {
+        String::from("success")
+    }
+    else {
+        format!("{} [{:#X}]", ResultDisplay(rc), rc)
+    }
+}
+
+pub fn format_timestamp(ticks: u64) -> String {
+    let micros = (ticks as u128 * 1000000 / SYSTEM_TICK_FREQUENCY as u128) as u64;
+    format!("{}.{:06}", micros / 1000000, micros % 1000000)
+}
+
+fn format_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+fn format_list<T: std::fmt::Debug>(items: &[T]) -> String {
+    format!("{:?}", items)
+}
+
+fn format_string(data: &[u8]) -> String {
+    let len = data.iter().position(|byte| *byte == 0).unwrap_or(data.len());
+    format!("{:?}", String::from_utf8_lossy(&data[..len]))
+}
+
+fn format_value(type_name: &str, data: &[u8]) -> String {
+    let formatted = match type_name {
+        "bool" => read_pod::<u8>(data).map(|value| format!("{}", value != 0)),
+        "u8" => read_pod::<u8>(data).map(|value| format!("{}", value)),
+        "u16" => read_pod::<u16>(data).map(|value| format!("{}", value)),
+        "u32" => read_pod::<u32>(data).map(|value| format!("{}", value)),
+        "i32" => read_pod::<i32>(data).map(|value| format!("{}", value)),
+        "u64" | "usize" => read_pod::<u64>(data).map(|value| format!("{}", value)),
+        "ResultCode" => read_pod::<u32>(data).map(format_result),
+        "ApplicationId" => read_pod::<ApplicationId>(data).map(|app_id| format!("{}", app_id)),
+        "ProgramId" => read_pod::<ProgramId>(data).map(|program_id| format!("{}", program_id)),
+        "StorageId" => data.first().and_then(|raw| StorageId::from_raw(*raw)).map(|storage_id| format!("{:?}", storage_id)),
+        "Uid" => read_pod::<Uid>(data).map(|uid| format!("{:016X}{:016X}", uid.hi, uid.lo)),
+        "ApplicationRecord" => read_pod::<ApplicationRecord>(data).map(|record| format!("{:?}", record)),
+        "ApplicationLaunchInfo" => read_launch_info(data).map(|info| format!("{:?}", info)),
+        _ => {
+            if type_name.starts_with("CString<") {
+                Some(format_string(data))
+            }
+            else {
+                None
+            }
+        }
+    };
+    formatted.unwrap_or_else(|| format_hex(data))
+}
+
+fn format_buffer_data(command_name: &str, param_name: &str, data: &[u8]) -> String {
+    if param_name.contains("app_id") {
+        return format_list(&read_pod_array::<ApplicationId>(data));
+    }
+
+    match command_name {
+        "list_application_record" => format_list(&read_pod_array::<ApplicationRecord>(data)),
+        "get_application_view" => format_list(&read_pod_array::<ApplicationView>(data)),
+        "get_application_rights_on_client" => format_list(&read_pod_array::<ApplicationRightsOnClient>(data)),
+        _ => format_hex(data)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecodedParam {
+    pub name: String,
+    pub type_name: String,
+    pub kind: TraceParamKind,
+    pub size: u32,
+    pub value: String
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecodedRecord {
+    pub timestamp: String,
+    pub program_id: ProgramId,
+    pub process_id: u64,
+    pub service: String,
+    pub interface: String,
+    pub command_id: u32,
+    pub command: String,
+    pub result: String,
+    pub inputs: Vec<DecodedParam>,
+    pub outputs: Vec<DecodedParam>
+}
+
+fn decode_param(command: Option<&CommandInfo>, type_info: Option<&(&str, &str)>, param: &TraceParam) -> DecodedParam {
+    let (name, type_name) = type_info.map(|(name, type_name)| (String::from(*name), String::from(*type_name))).unwrap_or_else(|| (String::from("?"), String::from("?")));
+    let command_name = command.map(|command| command.name).unwrap_or("");
+    let value = match param.kind {
+        TraceParamKind::Value => format_value(&type_name, &param.data),
+        TraceParamKind::InBuffer => format_buffer_data(command_name, &name, &param.data),
+        TraceParamKind::OutBuffer => format!("<{:#X} bytes>", param.size),
+        TraceParamKind::Handle | TraceParamKind::OutHandle => read_pod::<u32>(&param.data).map(|handle| format!("handle {:#X}", handle)).unwrap_or_else(|| format_hex(&param.data)),
+        TraceParamKind::OutValue => format_value(&type_name, &param.data),
+        TraceParamKind::OutBufferData => format_buffer_data(command_name, &name, &param.data),
+        TraceParamKind::OutObject => String::from("<object>")
+    };
+    DecodedParam { name, type_name, kind: param.kind, size: param.size, value }
+}
+
+pub fn decode_record(record: &TraceRecord) -> DecodedRecord {
+    let header = &record.header;
+    let interface = TraceInterface::from_raw(header.interface);
+    let command = interface.and_then(|interface| commands::find_command(interface, header.command_id));
+
+    let mut inputs: Vec<DecodedParam> = Vec::new();
+    let mut outputs: Vec<DecodedParam> = Vec::new();
+    let params = command.map(|command| command.params).unwrap_or(&[]);
+    let outputs_info = command.map(|command| command.outputs).unwrap_or(&[]);
+    let out_buf_params: Vec<&(&str, &str)> = params.iter().filter(|(_, type_name)| *type_name == "OutMapAliasBuffer").collect();
+    let mut out_idx: usize = 0;
+    let mut out_buf_idx: usize = 0;
+    for param in record.params.iter() {
+        match param.kind {
+            TraceParamKind::OutValue | TraceParamKind::OutHandle | TraceParamKind::OutObject => {
+                // Every output is its own parameter, in command order
+                outputs.push(decode_param(command, outputs_info.get(out_idx), param));
+                out_idx += 1;
+            },
+            TraceParamKind::OutBufferData => {
+                outputs.push(decode_param(command, out_buf_params.get(out_buf_idx).copied(), param));
+                out_buf_idx += 1;
+            },
+            _ => {
+                inputs.push(decode_param(command, params.get(inputs.len()), param));
+            }
+        }
+    }
+
+    DecodedRecord {
+        timestamp: format_timestamp(header.timestamp),
+        program_id: ProgramId(header.program_id),
+        process_id: header.process_id,
+        service: TRACE_SERVICE_NAMES.get(header.service_kind as usize).map(|name| String::from(*name)).unwrap_or_else(|| format!("<unknown service {}>", header.service_kind)),
+        interface: interface.map(|interface| String::from(interface.get_name())).unwrap_or_else(|| format!("<unknown interface {}>", header.interface)),
+        command_id: header.command_id,
+        command: command.map(|command| String::from(command.name)).unwrap_or_else(|| format!("<unknown command {}>", header.command_id)),
+        result: format_result(header.rc),
+        inputs,
+        outputs
+    }
+}
+
+pub fn render_text(record: &DecodedRecord) -> String {
+    let mut text = format!("[{}] {} {}::{} (#{}) from {} (pid {}) -> {}\n", record.timestamp, record.service, record.interface, record.command, record.command_id, record.program_id, record.process_id, record.result);
+    for input in record.inputs.iter() {
+        let _ = writeln!(text, "  in  {}: {} = {}", input.name, input.type_name, input.value);
+    }
+    for output in record.outputs.iter() {
+        let _ = writeln!(text, "  out {}: {} = {}", output.name, output.type_name, output.value);
+    }
+    text
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            },
+            c => escaped.push(c)
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn render_json_params(params: &[DecodedParam]) -> String {
+    let items: Vec<String> = params.iter().map(|param| format!("{{\"name\":{},\"type\":{},\"kind\":{},\"size\":{},\"value\":{}}}", escape_json(&param.name), escape_json(&param.type_name), escape_json(&format!("{:?}", param.kind)), param.size, escape_json(&param.value))).collect();
+    format!("[{}]", items.join(","))
+}
+
+// One JSON object per record (JSON lines)
+
+pub fn render_json(record: &DecodedRecord) -> String {
+    format!("{{\"timestamp\":{},\"program_id\":{},\"process_id\":{},\"service\":{},\"interface\":{},\"command_id\":{},\"command\":{},\"result\":{},\"inputs\":{},\"outputs\":{}}}",
+        escape_json(&record.timestamp),
+        escape_json(&format!("{}", record.program_id)),
+        record.process_id,
+        escape_json(&record.service),
+        escape_json(&record.interface),
+        record.command_id,
+        escape_json(&record.command),
+        escape_json(&record.result),
+        render_json_params(&record.inputs),
+        render_json_params(&record.outputs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace_format::{push_bytes, push_param};
+
+    fn make_trace(records: &[(TraceInterface, u32, u32, Vec<u8>)]) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        push_bytes(&TraceFileHeader { magic: TRACE_MAGIC, version: TRACE_VERSION }, &mut data);
+        for (interface, command_id, rc, params) in records {
+            let header = TraceRecordHeader {
+                timestamp: SYSTEM_TICK_FREQUENCY * 3 / 2,
+                program_id: 0x0100000000001000,
+                process_id: 0x51,
+                interface: *interface as u8,
+                service_kind: 0,
+                command_id: *command_id,
+                rc: *rc,
+                param_data_size: params.len() as u32,
+                ..Default::default()
+            };
+            push_bytes(&header, &mut data);
+            data.extend_from_slice(params);
+        }
+        data
+    }
+
+    fn make_list_application_record_params() -> Vec<u8> {
+        let records = [ApplicationRecord { app_id: ApplicationId(0x0100AAAA00000000), unk_type: 3, ..Default::default() }];
+        let mut record_data: Vec<u8> = Vec::new();
+        push_bytes(&records, &mut record_data);
+
+        let mut params: Vec<u8> = Vec::new();
+        push_param(TraceParamKind::Value, 4, &0u32.to_le_bytes(), &mut params);
+        push_param(TraceParamKind::OutBuffer, 0x180, &[], &mut params);
+        push_param(TraceParamKind::OutValue, 4, &1u32.to_le_bytes(), &mut params);
+        push_param(TraceParamKind::OutBufferData, 0x180, &record_data, &mut params);
+        params
+    }
+
+    #[test]
+    fn parses_records_and_params() {
+        let data = make_trace(&[(TraceInterface::ApplicationManager, 0, 0, make_list_application_record_params())]);
+        let records = parse_trace(&data).unwrap().records;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].header.program_id, 0x0100000000001000);
+        assert_eq!(records[0].params.len(), 4);
+        assert_eq!(records[0].params[1].kind, TraceParamKind::OutBuffer);
+        assert_eq!(records[0].params[1].size, 0x180);
+    }
+
+    #[test]
+    fn decodes_known_command() {
+        let data = make_trace(&[(TraceInterface::ApplicationManager, 0, 0, make_list_application_record_params())]);
+        let record = decode_record(&parse_trace(&data).unwrap().records[0]);
+        assert_eq!(record.command, "list_application_record");
+        assert_eq!(record.service, "ns:am2");
+        assert_eq!(record.timestamp, "1.500000");
+        assert_eq!(record.result, "success");
+
+        assert_eq!(record.inputs.len(), 2);
+        assert_eq!(record.inputs[0].name, "entry_offset");
+        assert_eq!(record.inputs[0].value, "0");
+        assert_eq!(record.inputs[1].value, "<0x180 bytes>");
+
+        assert_eq!(record.outputs.len(), 2);
+        assert_eq!(record.outputs[0].name, "count");
+        assert_eq!(record.outputs[0].value, "1");
+        assert_eq!(record.outputs[1].name, "out_record_buf");
+        assert!(record.outputs[1].value.contains("app_id: 0x0100AAAA00000000"));
+    }
+
+    #[test]
+    fn decodes_multiple_outputs() {
+        let mut params: Vec<u8> = Vec::new();
+        push_param(TraceParamKind::Value, 8, &0x0100AAAA00000000u64.to_le_bytes(), &mut params);
+        push_param(TraceParamKind::OutHandle, 4, &0x1234u32.to_le_bytes(), &mut params);
+        push_param(TraceParamKind::OutObject, 0, &[], &mut params);
+
+        let data = make_trace(&[(TraceInterface::ApplicationManager, 30, 0, params)]);
+        let record = decode_record(&parse_trace(&data).unwrap().records[0]);
+        assert_eq!(record.command, "request_application_update_info");
+        assert_eq!(record.inputs.len(), 1);
+        assert_eq!(record.outputs.len(), 2);
+        assert_eq!(record.outputs[0].name, "event");
+        assert_eq!(record.outputs[0].value, "handle 0x1234");
+        assert_eq!(record.outputs[1].name, "val");
+        assert_eq!(record.outputs[1].value, "<object>");
+    }
+
+    #[test]
+    fn decodes_launch_info() {
+        let mut info_data: Vec<u8> = vec![0; std::mem::size_of::<ApplicationLaunchInfo>()];
+        info_data[..8].copy_from_slice(&0x0100BBBB00000000u64.to_le_bytes());
+        info_data[0x10] = StorageId::SdCard as u8;
+        assert!(format_value("ApplicationLaunchInfo", &info_data).contains("app_storage_id: SdCard"));
+
+        // Invalid enum values must never be read as the struct itself
+        info_data[0x10] = 0xFF;
+        assert_eq!(format_value("ApplicationLaunchInfo", &info_data), format_hex(&info_data));
+    }
+
+    #[test]
+    fn renders_failures_and_unknown_commands() {
+        let data = make_trace(&[(TraceInterface::ApplicationManager, 0xFFFF, 0x1F4, Vec::new())]);
+        let record = decode_record(&parse_trace(&data).unwrap().records[0]);
+        assert_eq!(record.command, "<unknown command 65535>");
+        assert_eq!(record.result, "2500-0000 [0x1F4]");
+        assert!(render_text(&record).starts_with("[1.500000] ns:am2 IApplicationManagerInterface::<unknown command 65535> (#65535) from 0x0100000000001000 (pid 81)"));
+    }
+
+    #[test]
+    fn renders_valid_json() {
+        let data = make_trace(&[(TraceInterface::ApplicationManager, 0, 0, make_list_application_record_params())]);
+        let json = render_json(&decode_record(&parse_trace(&data).unwrap().records[0]));
+        assert!(json.starts_with("{\"timestamp\":\"1.500000\",\"program_id\":\"0x0100000000001000\",\"process_id\":81,"));
+        assert!(json.contains("\"command\":\"list_application_record\""));
+        assert_eq!(escape_json("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+
+    #[test]
+    fn rejects_invalid_traces() {
+        assert_eq!(parse_trace(&[0; 4]), Err(DecodeError::Truncated(0)));
+        assert_eq!(parse_trace(&[0; 8]), Err(DecodeError::InvalidMagic(0)));
+
+    }
+
+    #[test]
+    fn keeps_records_before_truncation() {
+        let params = make_list_application_record_params();
+        let mut data = make_trace(&[(TraceInterface::ApplicationManager, 0, 0, params.clone()), (TraceInterface::ApplicationManager, 0, 0, params)]);
+        let full_len = data.len();
+        data.truncate(full_len - 1);
+
+        let trace = parse_trace(&data).unwrap();
+        assert_eq!(trace.records.len(), 1);
+        let record_size = (full_len - std::mem::size_of::<TraceFileHeader>()) / 2;
+        assert_eq!(trace.truncated_size, record_size - 1);
+
+        // Even a header cut short
+        data.truncate(full_len - record_size + 4);
+        let trace = parse_trace(&data).unwrap();
+        assert_eq!(trace.records.len(), 1);
+        assert_eq!(trace.truncated_size, 4);
+    }
+}
+
+pub mod commands;
@@ -0,0 +1,301 @@
+use crate::trace_format::TraceInterface;
+
+// Mirrors the mitm command tables and the ns interface definitions, keep them in sync
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct CommandInfo {
+    pub interface: TraceInterface,
+    pub id: u32,
+    pub name: &'static str,
+    pub params: &'static [(&'static str, &'static str)],
+    pub outputs: &'static [(&'static str, &'static str)]
+}
+
+pub const COMMANDS: &[CommandInfo] = &[
+    CommandInfo { interface: TraceInterface::ServiceGetter, id: 7989, name: "get_read_only_application_control_data_interface", params: &[], outputs: &[("intf", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ServiceGetter, id: 7996, name: "get_application_manager_interface", params: &[], outputs: &[("intf", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ReadOnlyApplicationControlData, id: 0, name: "get_application_control_data", params: &[("source", "ApplicationControlSource"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("size", "u32")] },
+    CommandInfo { interface: TraceInterface::ReadOnlyApplicationControlData, id: 1, name: "get_application_desired_language", params: &[("lang_bitmask", "u8")], outputs: &[("lang_idx", "u8")] },
+    CommandInfo { interface: TraceInterface::ReadOnlyApplicationControlData, id: 2, name: "convert_application_language_to_language_code", params: &[("app_lang", "u8")], outputs: &[("lang_code", "CString<0x8>")] },
+    CommandInfo { interface: TraceInterface::ReadOnlyApplicationControlData, id: 3, name: "convert_language_code_to_application_language", params: &[("lang_code", "CString<0x8>")], outputs: &[("app_lang", "u8")] },
+    CommandInfo { interface: TraceInterface::ReadOnlyApplicationControlData, id: 4, name: "select_application_desired_language", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 0, name: "list_application_record", params: &[("entry_offset", "u32"), ("out_record_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1, name: "generate_application_record_count", params: &[], outputs: &[("unk_count", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2, name: "get_application_record_update_system_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3, name: "get_application_view_deprecated", params: &[("in_app_ids", "InMapAliasBuffer"), ("out_views", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 4, name: "delete_application_entity", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 5, name: "delete_application_completely", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 6, name: "is_any_application_entity_redundant", params: &[], outputs: &[("redundant", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 7, name: "delete_redundant_application_entity", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 8, name: "is_application_entity_movable", params: &[("storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[("movable", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 9, name: "move_application_entity", params: &[("storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 11, name: "calculate_application_occupied_size", params: &[("app_id", "ApplicationId")], outputs: &[("size", "ApplicationOccupiedSize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 16, name: "push_application_record", params: &[("last_modified_event", "u8"), ("app_id", "ApplicationId"), ("record_buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 17, name: "list_application_record_content_meta", params: &[("offset", "u64"), ("app_id", "ApplicationId"), ("out_meta_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 19, name: "launch_application_old", params: &[("app_id", "ApplicationId")], outputs: &[("process_id", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 21, name: "get_application_content_path", params: &[("meta_type", "ContentMetaType"), ("app_id", "ApplicationId"), ("out_path", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 22, name: "terminate_application", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 23, name: "resolve_application_content_path", params: &[("meta_type", "ContentMetaType"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 26, name: "begin_install_application", params: &[("storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 27, name: "delete_application_record", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 30, name: "request_application_update_info", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 31, name: "request_update_application", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 32, name: "cancel_application_download", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 33, name: "resume_application_download", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 35, name: "update_version_list", params: &[("buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 36, name: "push_launch_version", params: &[("version", "u32"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 37, name: "list_required_version", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("unk_count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 38, name: "check_application_launch_version", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 39, name: "check_application_launch_rights", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 40, name: "get_application_logo_data", params: &[("app_id", "ApplicationId"), ("buf", "InMapAliasBuffer"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("unk", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 41, name: "calculate_application_download_required_size", params: &[("app_id", "ApplicationId")], outputs: &[("unk_1", "u64"), ("unk_2", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 42, name: "cleanup_sd_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 43, name: "check_sd_card_mount_status", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 44, name: "get_sd_card_mount_status_changed_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 45, name: "get_game_card_attachment_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 46, name: "get_game_card_attachment_info", params: &[], outputs: &[("unk_1", "u64"), ("unk_2", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 47, name: "get_total_space_size", params: &[("storage_id", "StorageId")], outputs: &[("size", "usize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 48, name: "get_free_space_size", params: &[("storage_id", "StorageId")], outputs: &[("size", "usize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 49, name: "get_sd_card_removed_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 52, name: "get_game_card_update_detection_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 53, name: "disable_application_auto_delete", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 54, name: "enable_application_auto_delete", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 55, name: "get_application_desired_language", params: &[("lang_bitmask", "u8")], outputs: &[("lang_idx", "u8")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 56, name: "set_application_terminate_result", params: &[("rc", "ResultCode"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 57, name: "clear_application_terminate_result", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 58, name: "get_last_sd_card_mount_unexpected_result", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 59, name: "convert_application_language_to_language_code", params: &[("app_lang", "u8")], outputs: &[("lang_code", "CString<0x8>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 60, name: "convert_language_code_to_application_language", params: &[("lang_code", "CString<0x8>")], outputs: &[("app_lang", "u8")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 61, name: "get_background_download_stress_task_info", params: &[], outputs: &[("unk_1", "u64"), ("unk_2", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 62, name: "get_game_card_stopper", params: &[], outputs: &[("stopper", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 63, name: "is_system_program_installed", params: &[("app_id", "ApplicationId")], outputs: &[("installed", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 64, name: "start_apply_delta_task", params: &[("unk_app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 65, name: "get_request_server_stopper", params: &[], outputs: &[("stopper", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 66, name: "get_background_apply_delta_stress_task_info", params: &[], outputs: &[("unk_1", "u64"), ("unk_2", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 67, name: "cancel_application_apply_delta", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 68, name: "resume_application_apply_delta", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 69, name: "calculate_application_apply_delta_required_size", params: &[("storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[("size", "usize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 70, name: "resume_all", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 71, name: "get_storage_size", params: &[("storage_id", "StorageId")], outputs: &[("total_size", "usize"), ("free_size", "usize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 80, name: "request_download_application", params: &[("unk_storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 81, name: "request_download_add_on_content", params: &[("unk_storage_id", "StorageId"), ("app_id", "ApplicationId"), ("buf", "InMapAliasBuffer")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 82, name: "download_application", params: &[("unk_storage_id", "StorageId"), ("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 83, name: "check_application_resume_rights", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 84, name: "get_dynamic_commit_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 85, name: "request_update_application_2", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 86, name: "enable_application_crash_report", params: &[("unk_enable", "bool")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 87, name: "is_application_crash_report_enabled", params: &[], outputs: &[("enabled", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 90, name: "boost_system_memory_resource_limit", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 91, name: "deprecated_launch_application", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 92, name: "get_running_application_program_id", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 93, name: "get_main_application_program_index", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 94, name: "launch_application", params: &[("program_idx", "u8"), ("info", "ApplicationLaunchInfo")], outputs: &[("process_id", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 95, name: "get_application_launch_info", params: &[("app_id", "ApplicationId")], outputs: &[("info", "ApplicationLaunchInfo")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 96, name: "acquire_application_launch_info", params: &[("app_id", "ApplicationId")], outputs: &[("info", "ApplicationLaunchInfo")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 97, name: "get_main_application_program_index_by_application_launch_info", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 98, name: "enable_application_all_thread_dump_on_crash", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 99, name: "launch_dev_menu", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 100, name: "reset_to_factory_settings", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 101, name: "reset_to_factory_settings_without_user_save_data", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 102, name: "reset_to_factory_settings_for_refurbishment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 103, name: "reset_to_factory_settings_with_platform_region", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 104, name: "reset_to_factory_settings_with_platform_region_authentication", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 105, name: "request_reset_to_factory_settings_securely", params: &[("unk", "u64"), ("unk_2", "CopyHandle")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 106, name: "request_reset_to_factory_settings_with_platform_region_authentication_securely", params: &[("unk", "u64"), ("unk_2", "u64"), ("unk_3", "CopyHandle")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 200, name: "calculate_user_save_data_statistics", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[("unk_3", "u64"), ("unk_4", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 201, name: "delete_user_save_data_all", params: &[("uid", "Uid")], outputs: &[("monitor", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 210, name: "delete_user_system_save_data", params: &[("uid", "Uid"), ("id", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 211, name: "delete_save_data", params: &[("space_id", "SaveDataSpaceId"), ("id", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 220, name: "unregister_network_service_account", params: &[("uid", "Uid")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 221, name: "unregister_network_service_account_with_user_save_data_deletion", params: &[("space_id", "SaveDataSpaceId"), ("id", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 300, name: "get_application_shell_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 301, name: "pop_application_shell_event_info", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("unk", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 302, name: "launch_library_applet", params: &[("program_id", "ProgramId")], outputs: &[("process_id", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 303, name: "terminate_library_applet", params: &[("program_id", "ProgramId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 304, name: "launch_system_applet", params: &[], outputs: &[("process_id", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 305, name: "terminate_system_applet", params: &[("program_id", "ProgramId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 306, name: "launch_overlay_applet", params: &[], outputs: &[("process_id", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 307, name: "terminate_overlay_applet", params: &[("program_id", "ProgramId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 400, name: "get_application_control_data", params: &[("source", "ApplicationControlSource"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("size", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 401, name: "invalidate_all_application_control_cache", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 402, name: "request_download_application_control_data", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 403, name: "get_max_application_control_cache_count", params: &[], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 404, name: "invalidate_application_control_cache", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 405, name: "list_application_control_cache_entry_info", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("unk", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 406, name: "get_application_control_property", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 407, name: "list_application_title", params: &[("app_id_buf", "InMapAliasBuffer"), ("source", "ApplicationControlSource"), ("tmem_handle", "CopyHandle"), ("tmem_size", "usize")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 408, name: "list_application_icon", params: &[("app_id_buf", "InMapAliasBuffer"), ("source", "ApplicationControlSource"), ("tmem_handle", "CopyHandle"), ("tmem_size", "usize")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 502, name: "request_check_game_card_registration", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 503, name: "request_game_card_registration_gold_point", params: &[("uid", "Uid"), ("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 504, name: "request_register_game_card", params: &[("unk", "u32"), ("uid", "Uid"), ("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 505, name: "get_game_card_mount_failure_event", params: &[], outputs: &[("event", "CopyHandle")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 506, name: "is_game_card_inserted", params: &[], outputs: &[("inserted", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 507, name: "ensure_game_card_access", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 508, name: "get_last_game_card_mount_failure_result", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 509, name: "list_application_id_on_game_card", params: &[("out_app_id_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 510, name: "get_game_card_platform_region", params: &[], outputs: &[("platform", "GameCardCompatibilityType")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 600, name: "count_application_content_meta", params: &[("app_id", "ApplicationId")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 601, name: "list_application_content_meta_status", params: &[("index", "u32"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 602, name: "list_available_add_on_content", params: &[("unk_1", "u64"), ("unk_2", "u64"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("unk_count", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 603, name: "get_owned_application_content_meta_status", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[("status", "ApplicationContentMetaStatus")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 604, name: "register_contents_external_key", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 605, name: "list_application_content_meta_status_with_rights_check", params: &[("index", "u32"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 606, name: "get_content_meta_storage", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[("storage", "StorageId")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 607, name: "list_available_add_on_content_new", params: &[("unk_1", "u64"), ("unk_2", "u64"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("unk_count", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 609, name: "list_availability_assured_add_on_content", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 700, name: "push_download_task_list", params: &[("in_buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 701, name: "clear_task_status_list", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 702, name: "request_download_task_list", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 703, name: "request_ensure_download_task", params: &[], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 704, name: "list_download_task_status", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 705, name: "request_download_task_list_data", params: &[], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 800, name: "request_version_list", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 801, name: "list_version_list", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 802, name: "request_version_list_data", params: &[], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 900, name: "get_application_record", params: &[("app_id", "ApplicationId")], outputs: &[("record", "ApplicationRecord")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 901, name: "get_application_record_property", params: &[("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 902, name: "enable_application_auto_update", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 903, name: "disable_application_auto_update", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 904, name: "touch_application", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 905, name: "request_application_update", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 906, name: "is_application_update_requested", params: &[("app_id", "ApplicationId")], outputs: &[("requested", "bool"), ("unk", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 907, name: "withdraw_application_update_request", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 908, name: "list_application_record_installed_content_meta", params: &[("unk_1", "u64"), ("unk_2", "u64"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 909, name: "withdraw_cleanup_add_on_contents_with_no_rights_recommendation", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 910, name: "has_application_record", params: &[("app_id", "ApplicationId")], outputs: &[("has", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 911, name: "set_pre_installed_application", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 912, name: "clear_pre_installed_application_flag", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 913, name: "list_all_application_record", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 914, name: "hide_application_record", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 915, name: "show_application_record", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 916, name: "is_application_auto_delete_disabled", params: &[("app_id", "ApplicationId")], outputs: &[("disabled", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1000, name: "request_verify_application_deprecated", params: &[("app_id", "ApplicationId"), ("tmem_handle", "CopyHandle"), ("tmem_size", "usize")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1001, name: "corrupt_application_for_debug", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1002, name: "request_verify_add_on_contents_rights", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1003, name: "request_verify_application", params: &[("unk", "u32"), ("app_id", "ApplicationId"), ("tmem", "CopyHandle"), ("tmem_size", "usize")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1004, name: "corrupt_content_for_debug", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1200, name: "needs_update_vulnerability", params: &[], outputs: &[("needs", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1300, name: "is_any_application_entity_installed", params: &[("app_id", "ApplicationId")], outputs: &[("installed", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1301, name: "delete_application_content_entities", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1302, name: "cleanup_unrecorded_application_entity", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1303, name: "cleanup_add_on_contents_with_no_rights", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1304, name: "delete_application_content_entity", params: &[("unk_1", "u64"), ("unk_2", "u64")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1308, name: "delete_application_completely_for_debug", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1309, name: "cleanup_unavailable_add_on_contents", params: &[("app_id", "ApplicationId"), ("uid", "Uid")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1310, name: "request_move_application_entity", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1311, name: "estimate_size_to_move", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1312, name: "has_movable_entity", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1313, name: "cleanup_orphan_contents", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1314, name: "check_precondition_satisfied_to_move", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1400, name: "prepare_shutdown", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1500, name: "format_sd_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1501, name: "needs_system_update_to_format_sd_card", params: &[], outputs: &[("needs", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1502, name: "get_last_sd_card_format_unexpected_result", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1504, name: "insert_sd_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1505, name: "remove_sd_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1506, name: "get_sd_card_startup_status", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1600, name: "get_system_seed_for_pseudo_device_id", params: &[], outputs: &[("system_seed", "[u8; 0x20]")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1601, name: "reset_system_seed_for_pseudo_device_id", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1700, name: "list_application_downloading_content_meta", params: &[("unk_1", "u64"), ("unk_2", "u64"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1701, name: "get_application_view", params: &[("in_app_ids", "InMapAliasBuffer"), ("out_views", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1702, name: "get_application_download_task_status", params: &[("app_id", "ApplicationId")], outputs: &[("status", "u8")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1703, name: "get_application_view_download_error_context", params: &[("app_id", "ApplicationId"), ("out_err_ctx_buf", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1704, name: "get_application_view_with_promotion_info", params: &[("in_app_ids", "InMapAliasBuffer"), ("out_data", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1705, name: "is_patch_auto_deletable_application", params: &[("app_id", "ApplicationId")], outputs: &[("is", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1800, name: "is_notification_setup_completed", params: &[], outputs: &[("completed", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1801, name: "get_last_notification_info_count", params: &[], outputs: &[("unk", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1802, name: "list_last_notification_info", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1803, name: "list_notification_task", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1900, name: "is_active_account", params: &[("unk", "u32")], outputs: &[("active", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1901, name: "request_download_application_prepurchased_rights", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1902, name: "get_application_ticket_info", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 1903, name: "request_download_application_prepurchased_rights_for_account", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2000, name: "get_system_delivery_info", params: &[("out_buf", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2001, name: "select_latest_system_delivery_info", params: &[("system_info_buf", "InMapAliasBuffer"), ("system_infos_buf", "InMapAliasBuffer"), ("app_infos_buf", "InMapAliasBuffer")], outputs: &[("index", "i32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2002, name: "verify_delivery_protocol_version", params: &[("system_info_buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2003, name: "get_application_delivery_info", params: &[("bitmask", "u32"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2004, name: "has_all_contents_to_deliver", params: &[("array_buf", "InMapAliasBuffer")], outputs: &[("has", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2005, name: "compare_application_delivery_info", params: &[("buf_1", "InMapAliasBuffer"), ("buf_2", "InMapAliasBuffer")], outputs: &[("cmp", "i32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2006, name: "can_deliver_application", params: &[("buf_1", "InMapAliasBuffer"), ("buf_2", "InMapAliasBuffer")], outputs: &[("can", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2007, name: "list_content_meta_key_to_deliver_application", params: &[("unk", "i32"), ("in_buf", "InMapAliasBuffer"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2008, name: "needs_system_update_to_deliver_application", params: &[("buf_1", "InMapAliasBuffer"), ("buf_2", "InMapAliasBuffer")], outputs: &[("needs", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2009, name: "estimate_required_size", params: &[("meta_key_buf", "InMapAliasBuffer")], outputs: &[("size", "usize")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2010, name: "request_receive_application", params: &[("storage_id", "StorageId"), ("port", "u16"), ("ipv4_addr", "u32"), ("app_id", "ApplicationId"), ("meta_keys_buf", "InMapAliasBuffer")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2011, name: "commit_receive_application", params: &[("app_id", "ApplicationId")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2012, name: "get_receive_application_progress", params: &[("app_id", "ApplicationId")], outputs: &[("progress", "ReceiveApplicationProgress")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2013, name: "request_send_application", params: &[("port", "u16"), ("ipv4_addr", "u32"), ("app_id", "ApplicationId"), ("meta_keys_buf", "InMapAliasBuffer")], outputs: &[("event", "CopyHandle"), ("async_rc", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2014, name: "get_send_application_progress", params: &[("app_id", "ApplicationId")], outputs: &[("progress", "SendApplicationProgress")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2015, name: "compare_system_delivery_info", params: &[("buf_1", "InMapAliasBuffer"), ("buf_2", "InMapAliasBuffer")], outputs: &[("cmp", "i32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2016, name: "list_not_committed_content_meta", params: &[("unk", "i32"), ("app_id", "ApplicationId"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2017, name: "recover_download_task", params: &[("unk", "u64"), ("array", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2018, name: "get_application_delivery_info_hash", params: &[("array", "InMapAliasBuffer")], outputs: &[("sha256_hash", "[u8; 0x20]")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2050, name: "get_application_rights_on_client", params: &[("flags", "u32"), ("app_id", "ApplicationId"), ("uid", "Uid"), ("out_buf", "OutMapAliasBuffer")], outputs: &[("count", "u32")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2051, name: "invalidate_rights_id_cache", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2100, name: "get_application_terminate_result", params: &[("app_id", "ApplicationId")], outputs: &[("rc", "ResultCode")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2101, name: "get_raw_application_terminate_result", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2150, name: "create_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2151, name: "destroy_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2152, name: "activate_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2153, name: "deactivate_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2154, name: "force_activate_rights_context_for_exit", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2155, name: "update_rights_environment_status", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2156, name: "create_rights_environment_for_micro_application_preomia", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2160, name: "add_target_application_to_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2161, name: "set_users_to_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2170, name: "get_rights_environment_status", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2171, name: "get_rights_environment_status_changed_event", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2180, name: "request_extend_expiration_in_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2181, name: "get_result_of_extend_expiration_in_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2182, name: "set_active_rights_context_using_state_to_rights_environment", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2190, name: "get_rights_environment_handle_for_application", params: &[("unk", "u64")], outputs: &[("unk_2", "u64")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2199, name: "get_rights_environment_count_for_debug", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2200, name: "get_game_card_application_copy_identifier", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2201, name: "get_installed_application_copy_identifier", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2250, name: "request_report_active_elicence", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2300, name: "list_event_log", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2350, name: "perform_auto_update_by_application_id", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2351, name: "request_no_download_rights_error_resolution", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2352, name: "request_resolve_no_download_rights_error", params: &[("app_id", "ApplicationId")], outputs: &[("event", "CopyHandle"), ("val", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2353, name: "get_application_download_task_info", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2354, name: "prioritize_application_background_task", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2355, name: "prefer_storage_efficient_update", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2356, name: "request_storage_efficient_update_preferible", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2400, name: "get_promotion_info", params: &[("app_id_buf", "InMapAliasBuffer"), ("uid_buf", "InMapAliasBuffer"), ("out_buf", "OutMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2401, name: "count_promotion_info", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2402, name: "list_promotion_info", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2403, name: "import_promotion_json_for_debug", params: &[("buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2404, name: "clear_promotion_info_for_debug", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2500, name: "confirm_available_time", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2510, name: "create_application_resource", params: &[], outputs: &[("resource", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2511, name: "get_application_resource", params: &[], outputs: &[("resource", "Shared<dyn IObject>")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2513, name: "launch_micro_application_preomia", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2514, name: "clear_task_of_async_task_manager", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2515, name: "cleanup_all_placeholder_and_fragments_if_no_task", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2516, name: "ensure_application_certificate", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2517, name: "create_application_instance", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2518, name: "update_qualification_for_debug", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2519, name: "is_qualification_transition_supported", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2520, name: "is_qualification_transition_supported_by_process_id", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2521, name: "get_rights_user_changed_event", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 2800, name: "get_application_id_of_preomia", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3000, name: "register_device_lock_key", params: &[("buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3001, name: "unregister_device_lock_key", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3002, name: "verify_device_lock_key", params: &[("buf", "InMapAliasBuffer")], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3003, name: "hide_application_icon", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3004, name: "show_application_icon", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3005, name: "hide_application_title", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3006, name: "show_application_title", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3007, name: "enable_game_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3008, name: "disable_game_card", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3009, name: "enable_local_content_share", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3010, name: "disable_local_content_share", params: &[], outputs: &[] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3011, name: "is_application_icon_hidden", params: &[], outputs: &[("hidden", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3012, name: "is_application_title_hidden", params: &[], outputs: &[("hidden", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3013, name: "is_game_card_enabled", params: &[], outputs: &[("enabled", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 3014, name: "is_local_content_share_enabled", params: &[], outputs: &[("enabled", "bool")] },
+    CommandInfo { interface: TraceInterface::ApplicationManager, id: 9999, name: "get_application_certificate", params: &[], outputs: &[] },
+];
+
+pub fn find_command(interface: TraceInterface, id: u32) -> Option<&'static CommandInfo> {
+    COMMANDS.iter().find(|command| (command.interface == interface) && (command.id == id))
+}
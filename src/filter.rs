@@ -0,0 +1,132 @@
+use alloc::vec::Vec;
+use crate::parse::{parse_id, strip_hex_prefix};
+use crate::types::ProgramId;
+
+// Rules are written as one of:
+// - "*": any program
+// - "0x0100000000001000": a single program
+// - "0x01000000000010**": a program ID where "*" digits match anything
+// IDs are right-aligned like numbers, so digits left out (as in "0x1000") are zeros, never wildcards
+// - "0x0100000000001000-0x0100000000001FFF": an inclusive program ID range
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ProgramIdRule {
+    Any,
+    Masked { value: u64, mask: u64 },
+    Range { start: u64, end: u64 }
+}
+
+impl ProgramIdRule {
+    fn parse_masked(rule: &str) -> Option<Self> {
+        let digits = strip_hex_prefix(rule);
+        if digits.is_empty() || (digits.len() > 16) {
+            return None;
+        }
+
+        let mut value: u64 = 0;
+        // Starting with a full mask, so that every digit not written stays fixed
+        let mut mask: u64 = u64::MAX;
+        for digit in digits.chars() {
+            value <<= 4;
+            mask <<= 4;
+            if digit != '*' {
+                value |= digit.to_digit(16)? as u64;
+                mask |= 0xF;
+            }
+        }
+        Some(ProgramIdRule::Masked { value, mask })
+    }
+
+    pub fn parse(rule: &str) -> Option<Self> {
+        let rule = rule.trim();
+        if rule == "*" {
+            Some(ProgramIdRule::Any)
+        }
+        else if let Some((start, end)) = rule.split_once('-') {
+            let start = parse_id(start)?;
+            let end = parse_id(end)?;
+            if start > end {
+                return None;
+            }
+            Some(ProgramIdRule::Range { start, end })
+        }
+        else {
+            Self::parse_masked(rule)
+        }
+    }
+
+    pub fn matches(&self, program_id: ProgramId) -> bool {
+        match *self {
+            ProgramIdRule::Any => true,
+            ProgramIdRule::Masked { value, mask } => (program_id.0 & mask) == value,
+            ProgramIdRule::Range { start, end } => (program_id.0 >= start) && (program_id.0 <= end)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ProgramFilter {
+    pub include: Vec<ProgramIdRule>,
+    pub exclude: Vec<ProgramIdRule>
+}
+
+impl ProgramFilter {
+    pub fn matches(&self, program_id: ProgramId) -> bool {
+        // No include rules means everyone is included, exclude rules always take priority
+        let included = self.include.is_empty() || self.include.iter().any(|rule| rule.matches(program_id));
+        let excluded = self.exclude.iter().any(|rule| rule.matches(program_id));
+        included && !excluded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(rule: &str, program_id: u64) -> bool {
+        ProgramIdRule::parse(rule).unwrap().matches(ProgramId(program_id))
+    }
+
+    #[test]
+    fn exact_rules() {
+        assert!(matches("0x0100000000001000", 0x0100000000001000));
+        assert!(matches("0100000000001000", 0x0100000000001000));
+        assert!(!matches("0x0100000000001000", 0x0100000000001001));
+    }
+
+    #[test]
+    fn wildcard_rules() {
+        assert!(matches("*", 0x0100000000001000));
+        assert!(matches("0x01000000000010**", 0x01000000000010AB));
+        assert!(!matches("0x01000000000010**", 0x0100000000001100));
+        assert!(matches("0x0100000000001000-0x0100000000001FFF", 0x0100000000001ABC));
+        assert!(!matches("0x0100000000001000-0x0100000000001FFF", 0x0100000000002000));
+    }
+
+    #[test]
+    fn short_rules_are_not_suffixes() {
+        // Leading zero dropped
+        assert!(matches("0x100000000001000", 0x0100000000001000));
+        assert!(!matches("0x100000000001000", 0x1100000000001000));
+        assert!(matches("0x1000", 0x1000));
+        assert!(!matches("0x1000", 0x0100000000001000));
+        assert!(matches("0x10**", 0x10AB));
+        assert!(!matches("0x10**", 0x01000000000010AB));
+    }
+
+    #[test]
+    fn invalid_rules() {
+        for rule in ["", "0x", "0x12G4", "0x01000000000010000", "0x2000-0x1000", "0x1000-", "**-0x1000"].iter() {
+            assert_eq!(ProgramIdRule::parse(rule), None, "{}", rule);
+        }
+    }
+
+    #[test]
+    fn filters() {
+        let filter = ProgramFilter { include: vec![ProgramIdRule::parse("0x01000000000010**").unwrap()], exclude: vec![ProgramIdRule::parse("0x0100000000001001").unwrap()] };
+        assert!(filter.matches(ProgramId(0x0100000000001000)));
+        assert!(!filter.matches(ProgramId(0x0100000000001001)));
+        assert!(!filter.matches(ProgramId(0x0100000000002000)));
+        assert!(ProgramFilter::default().matches(ProgramId(0x0100000000002000)));
+    }
+}
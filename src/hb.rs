@@ -1,6 +1,15 @@
 use alloc::vec::Vec;
+use alloc::string::String;
 use nx::result::*;
-use crate::ns::{ApplicationControlProperty, ApplicationId, ApplicationRecord, ApplicationView, ApplicationViewDeprecated, ApplicationViewWithPromotionInfo, PromotionInfo};
+use nashe::results::{ResultDescription, ResultDisplay};
+use nx::fs;
+use nx::sync;
+use nx::diag::log;
+use crate::logger;
+use crate::config;
+use crate::ns;
+use crate::order;
+use crate::ns::{ApplicationControlProperty, ApplicationId, ApplicationLaunchInfo, ApplicationRecord, ApplicationRightsOnClient, ApplicationTitle, ApplicationView, ApplicationViewDeprecated, ApplicationViewWithPromotionInfo, PromotionInfo, StorageId, Uid};
 
 pub const fn gen_application_view(app_id: ApplicationId) -> ApplicationView {
     ApplicationView {
@@ -43,6 +52,35 @@ pub const fn gen_application_record(app_id: ApplicationId) -> ApplicationRecord
     }
 }
 
+pub const fn gen_application_launch_info(app_id: ApplicationId) -> ApplicationLaunchInfo {
+    // NROs live on the SD card and never get updates
+    ApplicationLaunchInfo {
+        app_id,
+        version: 0,
+        pm_launch_flags: 0,
+        app_storage_id: StorageId::SdCard,
+        update_storage_id: StorageId::None,
+        other_data: [0; 0x2E]
+    }
+}
+
+pub const fn gen_application_rights_on_client(app_id: ApplicationId, uid: Uid) -> ApplicationRightsOnClient {
+    // Always launchable and resumable, for any user
+    ApplicationRightsOnClient {
+        app_id,
+        uid,
+        flags_1: 1,
+        flags_2: 0,
+        unk: [0, 0, 1, 0, 0, 0]
+    }
+}
+
+// Extra applications have no versions, updates nor rights to check before launching them
+
+pub fn check_extra_application_launch(app_id: ApplicationId) -> Result<()> {
+    find_extra_application(app_id).map(|_| ()).ok_or(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))
+}
+
 pub const fn convert_application_view_to_deprecated(view: ApplicationView) -> ApplicationViewDeprecated {
     ApplicationViewDeprecated {
         app_id: view.app_id,
@@ -59,11 +97,145 @@ pub const fn convert_application_view_to_deprecated(view: ApplicationView) -> Ap
     }
 }
 
-const TEST_APP_ID: ApplicationId = ApplicationId(0x0500ABBACDDCEFFE);
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExtraApplication {
+    pub app_id: ApplicationId,
+    pub nro_path: String
+}
+
+// The registry gets replaced by the rescan thread while the server reads it, hence the lock
+
+static mut G_EXTRA_APPLICATIONS: Vec<ExtraApplication> = Vec::new();
+static mut G_EXTRA_APPLICATIONS_LOCK: sync::Mutex = sync::Mutex::new(false);
+
+fn is_nro_file_name(name: &str) -> bool {
+    name.len() > 4 && name[name.len() - 4..].eq_ignore_ascii_case(".nro")
+}
+
+// A broken entry (or an unreadable subdirectory) is just skipped, the rest of the directory is still worth scanning
+
+fn scan_nro_files(dir: String, depth: u32, nro_paths: &mut Vec<String>) -> Result<()> {
+    let mut dir_obj = fs::open_directory(dir.clone(), fs::DirectoryOpenMode::ReadDirectories() | fs::DirectoryOpenMode::ReadFiles())?;
+    while let Some(entry) = dir_obj.read_next()? {
+        let name = match entry.name.get_string() {
+            Ok(name) => name,
+            Err(rc) => {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Skipping an entry of '{}' with an invalid name: {}\n", dir, ResultDisplay(rc.get_value()));
+                continue;
+            }
+        };
+        let path = format!("{}/{}", dir, name);
+        match entry.entry_type {
+            fs::DirectoryEntryType::Directory => {
+                // Homebrew is usually laid out as "switch/<name>/<name>.nro", no need to go deeper than that
+                if depth > 0 {
+                    if let Err(rc) = scan_nro_files(path.clone(), depth - 1, nro_paths) {
+                        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Skipping homebrew directory '{}': {}\n", path, ResultDisplay(rc.get_value()));
+                    }
+                }
+            },
+            fs::DirectoryEntryType::File => {
+                if is_nro_file_name(&name) {
+                    nro_paths.push(path);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Kept across rescans (which only ever happen one at a time), so that the ID map is only read at boot
+// It's only written back when an ID was actually allocated
+
+static mut G_ID_ALLOCATOR: Option<id::IdAllocator> = None;
+
+fn scan_extra_applications() -> Result<Vec<ExtraApplication>> {
+    let mut nro_paths: Vec<String> = Vec::new();
+    for homebrew_dir in config::get().homebrew_dirs.iter() {
+        if let Err(rc) = scan_nro_files(homebrew_dir.clone(), 1, &mut nro_paths) {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to scan homebrew directory '{}': {}\n", homebrew_dir, ResultDisplay(rc.get_value()));
+        }
+    }
+
+    let id_allocator = unsafe {
+        G_ID_ALLOCATOR.get_or_insert_with(id::IdAllocator::new)
+    };
+    id_allocator.refresh_real_application_ids()?;
+    let mut extra_apps: Vec<ExtraApplication> = Vec::new();
+    for nro_path in nro_paths {
+        let app_id = match id_allocator.allocate(&nro_path) {
+            Ok(app_id) => app_id,
+            Err(rc) => {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Skipping NRO '{}', unable to allocate an ID for it: {}\n", nro_path, ResultDisplay(rc.get_value()));
+                continue;
+            }
+        };
+
+        extra_apps.push(ExtraApplication { app_id, nro_path });
+    }
+
+    // The IDs are allocated in memory anyway, saving them is retried on the next rescan
+    if let Err(rc) = id_allocator.save() {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to save the application ID map: {}\n", ResultDisplay(rc.get_value()));
+    }
+    Ok(extra_apps)
+}
+
+// Rescans the homebrew directories, returning whether the extra applications changed
+
+pub fn rescan() -> Result<bool> {
+    let extra_apps = scan_extra_applications()?;
+    let old_extra_apps = unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_EXTRA_APPLICATIONS_LOCK);
+        if G_EXTRA_APPLICATIONS == extra_apps {
+            return Ok(false);
+        }
+        core::mem::replace(&mut G_EXTRA_APPLICATIONS, extra_apps.clone())
+    };
+
+    for extra_app in extra_apps.iter().filter(|extra_app| !old_extra_apps.contains(extra_app)) {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Registered NRO '{}' as extra application {:?}\n", extra_app.nro_path, extra_app.app_id);
+    }
+    for extra_app in old_extra_apps.iter().filter(|extra_app| !extra_apps.contains(extra_app)) {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Unregistered NRO '{}' (extra application {:?})\n", extra_app.nro_path, extra_app.app_id);
+    }
+
+    // Let callers know they need to list records again (and re-sort them)
+    order::invalidate_title_cache();
+    ns::event::signal_record_update();
+    Ok(true)
+}
+
+pub fn initialize() -> Result<()> {
+    rescan().map(|_| ())
+}
+
+pub fn get_extra_applications() -> Vec<ExtraApplication> {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_EXTRA_APPLICATIONS_LOCK);
+        G_EXTRA_APPLICATIONS.clone()
+    }
+}
+
+pub fn find_extra_application(app_id: ApplicationId) -> Option<ExtraApplication> {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_EXTRA_APPLICATIONS_LOCK);
+        G_EXTRA_APPLICATIONS.iter().find(|extra_app| extra_app.app_id == app_id).cloned()
+    }
+}
 
 pub fn is_extra_application(app_id: ApplicationId) -> bool {
-    // TODO
-    app_id == TEST_APP_ID
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_EXTRA_APPLICATIONS_LOCK);
+        is_extra_application_in(&G_EXTRA_APPLICATIONS, app_id)
+    }
+}
+
+// For commands handling several IDs at once, against a single get_extra_applications() snapshot
+
+pub fn is_extra_application_in(extra_apps: &[ExtraApplication], app_id: ApplicationId) -> bool {
+    extra_apps.iter().any(|extra_app| extra_app.app_id == app_id)
 }
 
 pub fn get_extra_application_view(app_id: ApplicationId) -> Result<ApplicationView> {
@@ -71,7 +243,7 @@ pub fn get_extra_application_view(app_id: ApplicationId) -> Result<ApplicationVi
         Ok(gen_application_view(app_id))
     }
     else {
-        Err(ResultCode::new(0xBEEF1))
+        Err(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))
     }
 }
 
@@ -80,28 +252,72 @@ pub fn get_extra_application_view_with_promotion_info(app_id: ApplicationId) ->
         Ok(gen_application_view_with_promotion_info(app_id))
     }
     else {
-        Err(ResultCode::new(0xBEEF1))
+        Err(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))
     }
 }
 
-pub fn get_extra_application_control_data(app_id: ApplicationId) -> Result<(Vec<u8>, Vec<u8>)> {
+pub fn get_extra_application_record(app_id: ApplicationId) -> Result<ApplicationRecord> {
     if is_extra_application(app_id) {
-        // TODO
-        let icon_data = include_bytes!("nashe.jpg");
-        let nacp_data = include_bytes!("nashe.nacp");
+        Ok(gen_application_record(app_id))
+    }
+    else {
+        Err(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))
+    }
+}
 
-        if nacp_data.len() != core::mem::size_of::<ApplicationControlProperty>() {
-            Err(ResultCode::new(0xBEEF5))
-        }
-        else {
-            Ok((nacp_data.to_vec(), icon_data.to_vec()))
-        }
+// Used for NROs without an asset section (or with a broken one, like a NACP of the wrong size)
+
+const DEFAULT_NACP_DATA: &[u8] = include_bytes!("nashe.nacp");
+const DEFAULT_ICON_DATA: &[u8] = include_bytes!("nashe.jpg");
+
+fn read_nro_control_data(nro_path: String) -> Result<(Vec<u8>, Vec<u8>)> {
+    let mut nro = nro::NroFile::open(nro_path)?;
+    let nacp_data = nro.read_nacp()?;
+    if nacp_data.len() != core::mem::size_of::<ApplicationControlProperty>() {
+        return Err(ResultCode::new(ResultDescription::InvalidNacpSize.get_value()));
+    }
+    let icon_data = nro.read_icon()?;
+    Ok((nacp_data, icon_data))
+}
+
+pub fn get_extra_application_control_data(app_id: ApplicationId) -> Result<(Vec<u8>, Vec<u8>)> {
+    if let Some(extra_app) = find_extra_application(app_id) {
+        let (nacp_data, icon_data) = match read_nro_control_data(extra_app.nro_path.clone()) {
+            Ok(control_data) => control_data,
+            Err(rc) => {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to read assets of NRO '{}', using default ones: {}\n", extra_app.nro_path, ResultDisplay(rc.get_value()));
+                (DEFAULT_NACP_DATA.to_vec(), DEFAULT_ICON_DATA.to_vec())
+            }
+        };
+        Ok((nacp_data, icon_data))
     }
     else {
-        Err(ResultCode::new(0xBEEF2))
+        Err(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))
     }
 }
 
+pub fn get_extra_application_title(app_id: ApplicationId) -> Result<ApplicationTitle> {
+    let (nacp_data, _) = get_extra_application_control_data(app_id)?;
+    let nacp = unsafe {
+        core::ptr::read_unaligned(nacp_data.as_ptr() as *const ApplicationControlProperty)
+    };
+
+    // NROs rarely fill in more than one language, so just take the first one that's there
+    Ok(nacp.titles.iter().find(|title| title.name[0] != 0).copied().unwrap_or(nacp.titles[0]))
+}
+
+pub fn get_extra_application_icon(app_id: ApplicationId) -> Result<Vec<u8>> {
+    let (_, icon_data) = get_extra_application_control_data(app_id)?;
+    Ok(icon_data)
+}
+
 pub fn get_extra_application_records() -> Vec<ApplicationRecord> {
-    vec![gen_application_record(TEST_APP_ID)]
-}
\ No newline at end of file
+    get_extra_applications().iter().map(|extra_app| gen_application_record(extra_app.app_id)).collect()
+}
+
+pub mod nro;
+
+pub mod id;
+pub mod launch;
+
+pub mod rescan;
@@ -0,0 +1,167 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use core::sync::atomic::{AtomicBool, Ordering};
+use nx::result::*;
+use nashe::results::{ResultDescription, ResultDisplay};
+use nx::fs;
+use nx::diag::log;
+use crate::logger;
+use crate::ns::{self, ApplicationId, GetterServiceKind};
+
+// Extra application IDs are derived from the NRO path, within a prefix no real title uses
+
+const EXTRA_APPLICATION_ID_BASE: u64 = 0x0500000000000000;
+const EXTRA_APPLICATION_ID_MASK: u64 = 0x00FFFFFFFFFFF000;
+
+pub const ID_MAP_DIRECTORY: &str = "sdmc:/nashe";
+pub const ID_MAP_PATH: &str = "sdmc:/nashe/app_ids.txt";
+// The map is written here first and then renamed, so that a crash while saving never leaves a truncated map behind
+pub const ID_MAP_TEMP_PATH: &str = "sdmc:/nashe/app_ids.txt.tmp";
+
+const MAX_REMAP_ATTEMPTS: u32 = 0x40;
+
+const fn hash_bytes(hash: u64, data: &[u8]) -> u64 {
+    // FNV-1a, so that the same path always maps to the same ID across boots
+    let mut hash = hash;
+    let mut i: usize = 0;
+    while i < data.len() {
+        hash ^= data[i] as u64;
+        hash = hash.wrapping_mul(0x100000001B3);
+        i += 1;
+    }
+    hash
+}
+
+pub const fn derive_application_id(nro_path: &str, attempt: u32) -> ApplicationId {
+    let hash = hash_bytes(0xCBF29CE484222325, nro_path.as_bytes());
+    // Remapped IDs just keep hashing the attempt number on top of the path
+    let hash = if attempt > 0 { hash_bytes(hash, &attempt.to_le_bytes()) } else { hash };
+    ApplicationId(EXTRA_APPLICATION_ID_BASE | (hash & EXTRA_APPLICATION_ID_MASK))
+}
+
+fn list_real_application_ids() -> Result<Vec<ApplicationId>> {
+    let records = ns::client::list_all_application_records(GetterServiceKind::AM2)?;
+    Ok(records.iter().map(|record| record.app_id).collect())
+}
+
+// Real titles are only listed again once their records change (set from the record update event thread)
+
+static G_REAL_APPLICATION_IDS_STALE: AtomicBool = AtomicBool::new(true);
+
+pub fn invalidate_real_application_ids() {
+    G_REAL_APPLICATION_IDS_STALE.store(true, Ordering::SeqCst);
+}
+
+fn parse_id_map_line(line: &str) -> Option<(String, ApplicationId)> {
+    // Format: "<app-id-hex>=<nro-path>"
+    let (app_id_str, nro_path) = line.trim().split_once('=')?;
+    let app_id_str = app_id_str.trim();
+    let app_id_str = app_id_str.strip_prefix("0x").or_else(|| app_id_str.strip_prefix("0X")).unwrap_or(app_id_str);
+    let app_id = u64::from_str_radix(app_id_str, 16).ok()?;
+    Some((String::from(nro_path.trim()), ApplicationId(app_id)))
+}
+
+pub struct IdAllocator {
+    entries: Vec<(String, ApplicationId)>,
+    real_app_ids: Vec<ApplicationId>,
+    dirty: bool
+}
+
+impl IdAllocator {
+    pub fn new() -> Self {
+        let mut allocator = Self { entries: Vec::new(), real_app_ids: Vec::new(), dirty: false };
+        // A leftover temp map means we stopped right between removing the old map and renaming the new one
+        if let Err(rc) = allocator.load(ID_MAP_PATH).or_else(|_| allocator.load(ID_MAP_TEMP_PATH)) {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to load the application ID map, starting from scratch: {}\n", ResultDisplay(rc.get_value()));
+        }
+        allocator
+    }
+
+    // Needed before allocating, so that no extra application takes the ID of a real one
+
+    pub fn refresh_real_application_ids(&mut self) -> Result<()> {
+        if G_REAL_APPLICATION_IDS_STALE.swap(false, Ordering::SeqCst) {
+            match list_real_application_ids() {
+                Ok(real_app_ids) => self.real_app_ids = real_app_ids,
+                Err(rc) => {
+                    G_REAL_APPLICATION_IDS_STALE.store(true, Ordering::SeqCst);
+                    return Err(rc);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn load(&mut self, path: &str) -> Result<()> {
+        self.entries.clear();
+        let mut file = fs::open_file(String::from(path), fs::FileOpenOption::Read())?;
+        let mut data: Vec<u8> = vec![0; file.get_size()?];
+        file.read_array(&mut data)?;
+
+        let data_str = core::str::from_utf8(&data).map_err(|_| ResultCode::new(ResultDescription::IdMapParseError.get_value()))?;
+        for line in data_str.lines() {
+            match parse_id_map_line(line) {
+                Some(entry) => self.entries.push(entry),
+                None => {
+                    if !line.trim().is_empty() {
+                        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Ignoring malformed application ID map line '{}'\n", line);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        let mut data = String::new();
+        for (nro_path, app_id) in self.entries.iter() {
+            data.push_str(&format!("{:#018X}={}\n", app_id.0, nro_path));
+        }
+
+        let _ = fs::create_directory(String::from(ID_MAP_DIRECTORY));
+        let _ = fs::remove_file(String::from(ID_MAP_TEMP_PATH));
+        {
+            let mut file = fs::open_file(String::from(ID_MAP_TEMP_PATH), fs::FileOpenOption::Create() | fs::FileOpenOption::Write() | fs::FileOpenOption::Append())?;
+            file.write_array(data.as_bytes())?;
+        }
+        let _ = fs::remove_file(String::from(ID_MAP_PATH));
+        fs::rename_file(String::from(ID_MAP_TEMP_PATH), String::from(ID_MAP_PATH))?;
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    fn is_id_taken(&self, app_id: ApplicationId, nro_path: &str) -> bool {
+        self.real_app_ids.contains(&app_id) || self.entries.iter().any(|(entry_nro_path, entry_app_id)| (*entry_app_id == app_id) && (entry_nro_path != nro_path))
+    }
+
+    pub fn allocate(&mut self, nro_path: &str) -> Result<ApplicationId> {
+        if let Some(index) = self.entries.iter().position(|(entry_nro_path, _)| entry_nro_path == nro_path) {
+            let app_id = self.entries[index].1;
+            if !self.is_id_taken(app_id, nro_path) {
+                return Ok(app_id);
+            }
+
+            // A real title was installed with this ID since we last saw it, it needs a new one
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Stored ID {:?} of NRO '{}' now collides, remapping...\n", app_id, nro_path);
+            self.entries.remove(index);
+            self.dirty = true;
+        }
+
+        for attempt in 0..MAX_REMAP_ATTEMPTS {
+            let app_id = derive_application_id(nro_path, attempt);
+            if !self.is_id_taken(app_id, nro_path) {
+                self.entries.push((String::from(nro_path), app_id));
+                self.dirty = true;
+                return Ok(app_id);
+            }
+        }
+
+        Err(ResultCode::new(ResultDescription::ApplicationIdsExhausted.get_value()))
+    }
+}
@@ -0,0 +1,47 @@
+use alloc::string::String;
+use nx::result::*;
+use nashe::results::ResultDescription;
+use nx::fs;
+use nx::diag::log;
+use crate::logger;
+use crate::config;
+use crate::ns::ApplicationId;
+use super::ExtraApplication;
+
+// Extra applications are launched as the configured host title, whose loader picks the target NRO from here
+// Format: the NRO path on the first line, then its argv (the path itself, quoted, as hbmenu does)
+// Stock hbloader ignores this file (it always loads hbmenu), the host title needs a loader which reads it (see the README)
+
+pub const NEXT_LOAD_PATH: &str = "sdmc:/nashe/next_load.txt";
+
+pub fn get_host_application_id() -> Result<ApplicationId> {
+    config::get().host_app_id.ok_or(ResultCode::new(ResultDescription::HostApplicationNotConfigured.get_value()))
+}
+
+fn write_next_load(extra_app: &ExtraApplication) -> Result<()> {
+    let data = format!("{}\n\"{}\"\n", extra_app.nro_path, extra_app.nro_path);
+
+    let _ = fs::create_directory(String::from(super::id::ID_MAP_DIRECTORY));
+    let _ = fs::remove_file(String::from(NEXT_LOAD_PATH));
+    let mut file = fs::open_file(String::from(NEXT_LOAD_PATH), fs::FileOpenOption::Create() | fs::FileOpenOption::Write() | fs::FileOpenOption::Append())?;
+    file.write_array(data.as_bytes())?;
+    Ok(())
+}
+
+// Leaves the NRO to load for the host title, returning the host title to actually launch
+// Callers only count it as launched (for ordering) once launching the host title succeeded, and cancel it otherwise
+
+pub fn prepare_launch(app_id: ApplicationId) -> Result<ApplicationId> {
+    let extra_app = super::find_extra_application(app_id).ok_or(ResultCode::new(ResultDescription::ExtraApplicationNotFound.get_value()))?;
+    let host_app_id = get_host_application_id()?;
+    write_next_load(&extra_app)?;
+
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Launching NRO '{}' ({:?}) through host application {:?}\n", extra_app.nro_path, app_id, host_app_id);
+    Ok(host_app_id)
+}
+
+// Otherwise the next plain launch of the host title would load the NRO instead of hbmenu
+
+pub fn cancel_launch() {
+    let _ = fs::remove_file(String::from(NEXT_LOAD_PATH));
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use nx::result::*;
+use nashe::results::ResultDescription;
+use nx::fs;
+
+pub const NRO_MAGIC: u32 = u32::from_le_bytes(*b"NRO0");
+pub const ASSET_MAGIC: u32 = u32::from_le_bytes(*b"ASET");
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct NroStart {
+    pub unused: u32,
+    pub mod_offset: u32,
+    pub pad: [u8; 0x8]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct NroSegment {
+    pub file_offset: u32,
+    pub size: u32
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct NroHeader {
+    pub magic: u32,
+    pub version: u32,
+    pub size: u32,
+    pub flags: u32,
+    pub segments: [NroSegment; 3],
+    pub bss_size: u32,
+    pub reserved: u32,
+    pub module_id: [u8; 0x20],
+    pub dso_handle_offset: u32,
+    pub reserved_2: u32,
+    pub segments_2: [NroSegment; 3]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct AssetSection {
+    pub offset: u64,
+    pub size: u64
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct AssetHeader {
+    pub magic: u32,
+    pub version: u32,
+    pub icon: AssetSection,
+    pub nacp: AssetSection,
+    pub romfs: AssetSection
+}
+
+pub struct NroFile {
+    file: fs::File,
+    asset_header_offset: usize,
+    asset_header: AssetHeader
+}
+
+impl NroFile {
+    pub fn open(nro_path: String) -> Result<Self> {
+        let mut file = fs::open_file(nro_path, fs::FileOpenOption::Read())?;
+
+        let _start: NroStart = file.read_val()?;
+        let header: NroHeader = file.read_val()?;
+        if header.magic != NRO_MAGIC {
+            return Err(ResultCode::new(ResultDescription::InvalidNroMagic.get_value()));
+        }
+
+        // The asset section (if present) is appended right after the NRO image itself
+        let asset_header_offset = header.size as usize;
+        file.seek(asset_header_offset, fs::Whence::Start)?;
+        let asset_header: AssetHeader = file.read_val()?;
+        if asset_header.magic != ASSET_MAGIC {
+            return Err(ResultCode::new(ResultDescription::InvalidAssetSection.get_value()));
+        }
+
+        Ok(Self { file, asset_header_offset, asset_header })
+    }
+
+    fn read_asset_section(&mut self, section: AssetSection) -> Result<Vec<u8>> {
+        // Broken sizes must not make us allocate (and abort on) absurd amounts of memory
+        let file_size = self.file.get_size()? as u64;
+        let section_end = (self.asset_header_offset as u64).checked_add(section.offset).and_then(|offset| offset.checked_add(section.size));
+        if (section.size == 0) || section_end.map_or(true, |end| end > file_size) {
+            return Err(ResultCode::new(ResultDescription::InvalidAssetSection.get_value()));
+        }
+
+        let mut data: Vec<u8> = vec![0; section.size as usize];
+        self.file.seek(self.asset_header_offset + section.offset as usize, fs::Whence::Start)?;
+        self.file.read_array(&mut data)?;
+        Ok(data)
+    }
+
+    pub fn read_nacp(&mut self) -> Result<Vec<u8>> {
+        self.read_asset_section(self.asset_header.nacp)
+    }
+
+    pub fn read_icon(&mut self) -> Result<Vec<u8>> {
+        self.read_asset_section(self.asset_header.icon)
+    }
+}
@@ -0,0 +1,77 @@
+use nx::result::*;
+use nashe::results::ResultDisplay;
+use nx::svc;
+use nx::wait;
+use nx::thread;
+use nx::diag::log;
+use crate::logger;
+use crate::config;
+use crate::order;
+use crate::overrides;
+use crate::ns;
+
+// Background thread picking up NROs added/removed/renamed while we're running
+// It rescans every configured interval, or right away when requested
+
+static mut G_RESCAN_REQUEST_EVENT_HANDLE: svc::Handle = svc::INVALID_HANDLE;
+static mut G_RESCAN_REQUEST_CLIENT_EVENT_HANDLE: svc::Handle = svc::INVALID_HANDLE;
+static mut G_RESCAN_THREAD: Option<thread::Thread> = None;
+
+const RESCAN_THREAD_STACK_SIZE: usize = 0x4000;
+
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+
+fn rescan_thread_fn(_: &()) {
+    let request_event_handle = unsafe {
+        G_RESCAN_REQUEST_CLIENT_EVENT_HANDLE
+    };
+    let timeout = match config::get().rescan_interval_secs {
+        0 => -1,
+        interval_secs => interval_secs as i64 * NANOSECONDS_PER_SECOND
+    };
+
+    loop {
+        // Either a request or a timeout, both mean rescanning
+        let _ = wait::wait_handles(&[request_event_handle], timeout);
+        let _ = svc::reset_signal(request_event_handle);
+
+        match super::rescan() {
+            Ok(true) => diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Extra applications changed, signalled record update\n"),
+            Ok(false) => {},
+            Err(rc) => diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to rescan for homebrew NROs: {}\n", ResultDisplay(rc.get_value()))
+        }
+
+        if order::reload_order_file() {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Order file changed, signalled record update\n");
+            ns::event::signal_record_update();
+        }
+        if overrides::reload_overrides() {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Control data overrides changed, signalled record update\n");
+            ns::event::signal_record_update();
+        }
+    }
+}
+
+pub fn initialize() -> Result<()> {
+    let (event_handle, client_event_handle) = svc::create_event()?;
+    unsafe {
+        G_RESCAN_REQUEST_EVENT_HANDLE = event_handle;
+        G_RESCAN_REQUEST_CLIENT_EVENT_HANDLE = client_event_handle;
+    }
+
+    let mut rescan_thread = thread::Thread::new(rescan_thread_fn, &(), core::ptr::null_mut(), RESCAN_THREAD_STACK_SIZE, "nashe.Rescan")?;
+    rescan_thread.create_and_start(thread::PRIORITY_AUTO, -2)?;
+    unsafe {
+        G_RESCAN_THREAD = Some(rescan_thread);
+    }
+
+    Ok(())
+}
+
+pub fn request_rescan() {
+    unsafe {
+        if G_RESCAN_REQUEST_EVENT_HANDLE != svc::INVALID_HANDLE {
+            let _ = svc::signal_event(G_RESCAN_REQUEST_EVENT_HANDLE);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+#![cfg_attr(not(any(test, feature = "host")), no_std)]
+
+// Plain data definitions shared by the sysmodule and host-side tools, with no nx dependency
+
+extern crate alloc;
+
+pub mod types;
+
+pub mod trace_format;
+
+pub mod results;
+
+pub mod parse;
+
+pub mod filter;
+
+#[cfg(feature = "host")]
+pub mod decode;
@@ -0,0 +1,22 @@
+use nx::diag::log::{self, Logger};
+use crate::config;
+
+// LmLogger wrapper which drops anything below the configured log level
+
+pub struct FilteredLogger {
+    lm_logger: log::LmLogger
+}
+
+impl Logger for FilteredLogger {
+    fn new() -> Self {
+        Self {
+            lm_logger: log::LmLogger::new()
+        }
+    }
+
+    fn log(&mut self, metadata: &log::LogMetadata) {
+        if config::get_log_severity_level(metadata.severity) >= config::get_log_severity_level(config::get().log_level) {
+            self.lm_logger.log(metadata);
+        }
+    }
+}
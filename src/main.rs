@@ -12,6 +12,7 @@ extern crate alloc;
 extern crate paste;
 
 use nx::result::*;
+use nashe::results::ResultDisplay;
 use nx::util;
 use nx::thread;
 use nx::diag::assert;
@@ -21,11 +22,22 @@ use nx::version;
 use nx::fs;
 use core::panic;
 
+#[macro_use]
+mod trace;
+
 mod ns;
 use ns::IApplicationManagerInterface;
 
 mod hb;
 
+mod config;
+
+mod logger;
+
+mod overrides;
+
+mod order;
+
 const STACK_HEAP_SIZE: usize = 0x80000;
 static mut STACK_HEAP: [u8; STACK_HEAP_SIZE] = [0; STACK_HEAP_SIZE];
 
@@ -42,17 +54,37 @@ type Manager = server::ServerManager<POINTER_BUF_SIZE>;
 #[no_mangle]
 pub fn main() -> Result<()> {
     thread::get_current_thread().name.set_str("nashe.Main")?;
-    diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "[nashe] Hello there!\n");
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Hello there!\n");
 
     fs::initialize()?;
     fs::mount_sd_card("sdmc")?;
-    ns::client::initialize()?;
-    diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "[nashe] Everything initialized!\n");
+    config::load();
+    trace::initialize();
+    let mitm_services = ns::client::initialize(&config::get().mitm_services)?;
+    if let Err(rc) = hb::initialize() {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to scan for homebrew NROs: {}\n", ResultDisplay(rc.get_value()));
+    }
+    order::reload_order_file();
+    ns::event::initialize()?;
+    if let Err(rc) = hb::rescan::initialize() {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to start rescanning for homebrew NROs: {}\n", ResultDisplay(rc.get_value()));
+    }
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Everything initialized!\n");
 
     let mut manager = Manager::new()?;
-    manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::AM2}>>()?;
+    for kind in mitm_services.iter() {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Registering mitm for {:?}...\n", kind);
+        match kind {
+            ns::GetterServiceKind::AM2 => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::AM2}>>()?,
+            ns::GetterServiceKind::EC => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::EC}>>()?,
+            ns::GetterServiceKind::RID => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::RID}>>()?,
+            ns::GetterServiceKind::RT => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::RT}>>()?,
+            ns::GetterServiceKind::WEB => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::WEB}>>()?,
+            ns::GetterServiceKind::RO => manager.register_mitm_service_server::<ns::mitm::ServiceGetterInterface<{ns::GetterServiceKind::RO}>>()?
+        }
+    }
 
-    diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "[nashe] Looping...\n");
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "[nashe] Looping...\n");
     manager.loop_process()?;
 
     // This won't be reached anyway...
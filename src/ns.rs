@@ -4,295 +4,16 @@ use nx::ipc::sf;
 use nx::ipc::server;
 use nx::ipc::sf::sm;
 use nx::diag::log;
+use crate::logger;
 use nx::util::CString;
-use core::fmt::{Display, Debug, Formatter, Result as FmtResult};
+use core::fmt::{Display, Formatter, Result as FmtResult};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationTitle {
-    pub name: CString<0x200>,
-    pub author: CString<0x100>
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug /*, Default */)]
-#[repr(C)]
-pub struct ApplicationControlProperty {
-    pub titles: [ApplicationTitle; 16],
-    pub todo_other_stuff: [u8; 0x1000]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(u8)]
-pub enum ApplicationControlSource {
-    CacheOnly,
-    Storage,
-    StorageOnly
-}
-
-// Note: ncm type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
-#[repr(C)]
-pub struct ProgramId(pub u64);
-
-impl Display for ProgramId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:#018X}", self.0)
-    }
-}
-
-impl Debug for ProgramId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:#018X}", self.0)
-    }
-}
-
-// Note: ncm type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
-#[repr(C)]
-pub struct ApplicationId(pub u64);
-
-impl Display for ApplicationId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:#018X}", self.0)
-    }
-}
-
-impl Debug for ApplicationId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "{:#018X}", self.0)
-    }
-}
-
-// Note: ncm type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(u8)]
-pub enum StorageId {
-    #[default]
-    None = 0,
-    Host = 1,
-    GameCard = 2,
-    BuiltInSystem = 3,
-    BuiltInUser = 4,
-    SdCard = 5,
-    Any = 6
-}
-
-// Note: ncm type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(u8)]
-pub enum ContentMetaType {
-    #[default]
-    Unknown = 0x0,
-    SystemProgram = 0x1,
-    SystemData = 0x2,
-    SystemUpdate = 0x3,
-    BootImagePackage = 0x4,
-    BootImagePackageSafe = 0x5,
-    Application = 0x80,
-    Patch = 0x81,
-    AddOnContent = 0x82,
-    Delta = 0x83
-}
+pub use nashe::types::*;
 
 // Note: ncm type, not ns one
 
 pub type ContentPath = CString<0x301>;
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationRecord {
-    pub app_id: ApplicationId,
-    pub unk_type: u8,
-    pub unk_2: u8,
-    pub unk_3: [u8; 0x6],
-    pub unk_4: u8,
-    pub unk_reserved: [u8; 0x7]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationView {
-    pub app_id: ApplicationId,
-    pub unk_1: u32,
-    pub unk_flags: u32,
-    pub unk_2: [u8; 0x10],
-    pub unk_3: u32,
-    pub unk_4: u16,
-    pub unk_reserved: [u8; 0x2],
-    pub unk_6: [u8; 0x8],
-    pub unk_7: [u8; 0x10],
-    pub unk_8: u32,
-    pub unk_9: u8,
-    pub unk_reserved_2: [u8; 0xB]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationViewDeprecated {
-    pub app_id: ApplicationId,
-    pub unk_1: u32,
-    pub unk_flags: u32,
-    pub unk_2: [u8; 0x10],
-    pub unk_3: u32,
-    pub unk_4: u16,
-    pub unk_reserved: [u8; 0x2],
-    pub unk_7: [u8; 0x10],
-    pub unk_8: u32,
-    pub unk_9: u8,
-    pub unk_reserved_3: [u8; 0x3]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(C)]
-pub struct ApplicationOccupiedSize {
-    pub unk_data: [u8; 0x80]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(C)]
-pub struct ApplicationLaunchInfo {
-    pub app_id: ApplicationId,
-    pub version: u32,
-    pub pm_launch_flags: u32 /* */,
-    pub app_storage_id: StorageId,
-    pub update_storage_id: StorageId,
-    pub other_data: [u8; 0x2E]
-}
-
-// Note: account type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct Uid {
-    pub hi: u64,
-    pub lo: u64
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(C)]
-pub struct ProgressForDeleteUserSaveDataAll {
-    pub data: [u8; 0x28]
-}
-
-// Note: fs type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(u8)]
-pub enum SaveDataSpaceId {
-    System = 0,
-    User = 1,
-    SdSystem = 2,
-    Temporary = 3,
-    SdUser = 4,
-    ProperSystem = 100,
-    SafeMode = 101
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationContentMetaStatus {
-    pub meta_type: ContentMetaType,
-    pub storage: StorageId,
-    pub unk: u8, /**/
-    pub pad: u8,
-    pub version: u32,
-    pub app_id: ApplicationId
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct DownloadTaskStatus {
-    pub data: [u8; 0x20]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct VersionListData {
-    pub data: [u8; 0x20 /**/]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct PromotionInfo {
-    pub todo_data: [u8; 0x20 /**/]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationViewWithPromotionInfo {
-    pub view: ApplicationView,
-    pub info: PromotionInfo
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(C)]
-pub struct SystemDeliveryInfo {
-    pub system_delivery_protocol_version: u32,
-    pub app_delivery_protocol_version: u32,
-    pub has_exfat: bool,
-    pub pad: [u8; 0x3],
-    pub system_update_meta_version: u32,
-    pub system_update_meta_id: u64,
-    pub fw_variation_id: u8,
-    pub updatable_fw_group_id: u8,
-    pub platform_region: u8,
-    pub unused: [u8; 0xC5],
-    pub hmac_sha256_above: [u8; 0x20]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(C)]
-pub struct ApplicationDeliveryInfo {
-    pub unk: [u8; 0x8],
-    pub app_id: ApplicationId,
-    pub app_version: u32,
-    pub unk_2: [u8; 0x4],
-    pub required_system_version: u32,
-    pub unk_3: [u8; 0x4],
-    pub unk_4: [u8; 0xC0],
-    pub hmac_sha256_above: [u8; 0x20]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ReceiveApplicationProgress {
-    pub data: [u8; 0x10]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct SendApplicationProgress {
-    pub data: [u8; 0x10]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct ApplicationRightsOnClient {
-    pub app_id: ApplicationId,
-    pub uid: Uid,
-    pub flags_1: u8,
-    pub flags_2: u8,
-    pub unk: [u8; 0x6]
-}
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
-#[repr(C)]
-pub struct NoDownloadRightsErrorResolution {
-}
-
-// Note: fs type, not ns one
-
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
-#[repr(u8)]
-pub enum GameCardCompatibilityType {
-    Global = 0,
-    China = 1
-}
-
 pub trait IServiceGetterInterface {
     ipc_cmif_interface_define_command!(get_read_only_application_control_data_interface: () => (intf: Shared<dyn sf::IObject>));
     ipc_cmif_interface_define_command!(get_application_manager_interface: () => (intf: Shared<dyn sf::IObject>));
@@ -624,7 +345,31 @@ pub trait IApplicationManagerInterface {
     /**/ ipc_cmif_interface_define_command!(get_application_certificate: () => ());
 }
 
+// Note: nashe type, identifies who opened a mitm'd session
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct CallerInfo {
+    pub program_id: ProgramId,
+    pub process_id: u64
+}
+
+impl CallerInfo {
+    pub const fn from_process_info(info: sm::MitmProcessInfo) -> Self {
+        Self {
+            program_id: ProgramId(info.program_id),
+            process_id: info.process_id
+        }
+    }
+}
+
+impl Display for CallerInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} (pid {})", self.program_id, self.process_id)
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(usize)]
 pub enum GetterServiceKind {
     AM2,
     EC,
@@ -634,6 +379,10 @@ pub enum GetterServiceKind {
     RO
 }
 
+pub const GETTER_SERVICE_KIND_COUNT: usize = 6;
+
+pub const ALL_GETTER_SERVICE_KINDS: [GetterServiceKind; GETTER_SERVICE_KIND_COUNT] = [GetterServiceKind::AM2, GetterServiceKind::EC, GetterServiceKind::RID, GetterServiceKind::RT, GetterServiceKind::WEB, GetterServiceKind::RO];
+
 pub const fn get_getter_service_name<const K: GetterServiceKind>() -> &'static str {
     match K {
         GetterServiceKind::AM2 => nul!("ns:am2"),
@@ -647,4 +396,12 @@ pub const fn get_getter_service_name<const K: GetterServiceKind>() -> &'static s
 
 pub mod client;
 
-pub mod mitm;
\ No newline at end of file
+pub mod mitm;
+
+pub mod buf;
+
+pub mod tmem;
+
+pub mod local;
+
+pub mod event;
@@ -0,0 +1,75 @@
+use nx::result::*;
+use nashe::results::ResultDescription;
+use nx::ipc::sf;
+
+// Writes into a caller's output buffer, never past its size
+
+pub struct OutBufferWriter {
+    buf: sf::OutMapAliasBuffer,
+    offset: usize
+}
+
+impl OutBufferWriter {
+    pub fn new(buf: sf::OutMapAliasBuffer) -> Self {
+        Self { buf, offset: 0 }
+    }
+
+    #[inline]
+    pub fn get_remaining_size(&self) -> usize {
+        self.buf.size.saturating_sub(self.offset)
+    }
+
+    #[inline]
+    pub fn get_remaining_count<T>(&self) -> usize {
+        self.get_remaining_size() / core::mem::size_of::<T>()
+    }
+
+    pub fn seek(&mut self, offset: usize) -> Result<()> {
+        if offset > self.buf.size {
+            return Err(ResultCode::new(ResultDescription::BufferTooSmall.get_value()));
+        }
+
+        self.offset = offset;
+        Ok(())
+    }
+
+    // Zeroes the whole buffer, without moving the write offset
+
+    pub fn clear(&mut self) {
+        unsafe {
+            core::ptr::write_bytes(self.buf.buf as *mut u8, 0, self.buf.size);
+        }
+    }
+
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<()> {
+        if data.len() > self.get_remaining_size() {
+            return Err(ResultCode::new(ResultDescription::BufferTooSmall.get_value()));
+        }
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(data.as_ptr(), (self.buf.buf as *mut u8).add(self.offset), data.len());
+        }
+        self.offset += data.len();
+        Ok(())
+    }
+
+    pub fn write<T: Copy>(&mut self, t: &T) -> Result<()> {
+        let t_bytes = unsafe {
+            core::slice::from_raw_parts(t as *const T as *const u8, core::mem::size_of::<T>())
+        };
+        self.write_bytes(t_bytes)
+    }
+
+    // Writes as many items as fit, returning how many were written
+
+    pub fn write_truncated<'a, T: Copy + 'a>(&mut self, items: impl Iterator<Item = &'a T>) -> usize {
+        let mut count: usize = 0;
+        for item in items.take(self.get_remaining_count::<T>()) {
+            if self.write(item).is_err() {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+}
@@ -1,6 +1,8 @@
 use super::*;
+use alloc::vec::Vec;
 use nx::ipc::sf;
 use nx::service;
+use nashe::results::ResultDisplay;
 
 pub struct ReadOnlyApplicationControlDataInterface {
     session: sf::Session
@@ -40,8 +42,7 @@ impl IReadOnlyApplicationControlDataInterface for ReadOnlyApplicationControlData
     }
 
     fn select_application_desired_language(&mut self) -> Result<()> {
-        panic!("Unsupported select_application_desired_language")
-        // ipc_client_send_request_command!([self.session.object_info; 4] () => ())
+        ipc_client_send_request_command!([self.session.object_info; 4] () => ())
     }
 }
 
@@ -339,6 +340,18 @@ impl service::IClientObject for ApplicationManagerInterface {
     }
 }
 
+// Same commands as list_application_title/list_application_icon, but with their async value usable on our side
+
+impl ApplicationManagerInterface {
+    pub fn list_application_title_value(&mut self, app_id_buf: sf::InMapAliasBuffer, source: ApplicationControlSource, tmem_handle: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<AsyncValue>)> {
+        ipc_client_send_request_command!([self.session.object_info; 407] (app_id_buf, source, tmem_handle, tmem_size) => (event: sf::CopyHandle, val: Shared<AsyncValue>))
+    }
+
+    pub fn list_application_icon_value(&mut self, app_id_buf: sf::InMapAliasBuffer, source: ApplicationControlSource, tmem_handle: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<AsyncValue>)> {
+        ipc_client_send_request_command!([self.session.object_info; 408] (app_id_buf, source, tmem_handle, tmem_size) => (event: sf::CopyHandle, val: Shared<AsyncValue>))
+    }
+}
+
 impl IApplicationManagerInterface for ApplicationManagerInterface {
     fn list_application_record(&mut self, entry_offset: u32, out_record_buf: sf::OutMapAliasBuffer) -> Result<u32> {
         ipc_client_send_request_command!([self.session.object_info; 0] (entry_offset, out_record_buf) => (count: u32))
@@ -1490,42 +1503,137 @@ impl<const K: GetterServiceKind> service::IService for ServiceGetterInterface<K>
 
 // Global implementation
 
-static mut G_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::AM2 }>> = Shared::empty();
+// Every getter service gets its own backing session, so that each mitm'd service forwards to the real one it replaces
 
-static mut G_RO_INTF: Shared<ReadOnlyApplicationControlDataInterface> = Shared::empty();
-static mut G_APP_MAN_INTF: Shared<ApplicationManagerInterface> = Shared::empty();
+static mut G_AM2_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::AM2 }>> = Shared::empty();
+static mut G_EC_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::EC }>> = Shared::empty();
+static mut G_RID_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::RID }>> = Shared::empty();
+static mut G_RT_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::RT }>> = Shared::empty();
+static mut G_WEB_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::WEB }>> = Shared::empty();
+static mut G_RO_SRV: Shared<ServiceGetterInterface<{ GetterServiceKind::RO }>> = Shared::empty();
 
-pub fn initialize() -> Result<()> {
-    unsafe {
-        if G_SRV.is_null() {
-            G_SRV = service::new_service_object()?;
+const EMPTY_RO_INTF: Shared<ReadOnlyApplicationControlDataInterface> = Shared::empty();
+const EMPTY_APP_MAN_INTF: Shared<ApplicationManagerInterface> = Shared::empty();
+
+static mut G_RO_INTFS: [Shared<ReadOnlyApplicationControlDataInterface>; GETTER_SERVICE_KIND_COUNT] = [EMPTY_RO_INTF; GETTER_SERVICE_KIND_COUNT];
+static mut G_APP_MAN_INTFS: [Shared<ApplicationManagerInterface>; GETTER_SERVICE_KIND_COUNT] = [EMPTY_APP_MAN_INTF; GETTER_SERVICE_KIND_COUNT];
+
+// Not every getter service is allowed to open both interfaces, keep the real result to hand it back to our clients
 
-            G_RO_INTF = G_SRV.get_read_only_application_control_data_interface()?.to();
-            G_APP_MAN_INTF = G_SRV.get_application_manager_interface()?.to();
+static mut G_RO_INTF_RCS: [ResultCode; GETTER_SERVICE_KIND_COUNT] = [ResultCode::new(0); GETTER_SERVICE_KIND_COUNT];
+static mut G_APP_MAN_INTF_RCS: [ResultCode; GETTER_SERVICE_KIND_COUNT] = [ResultCode::new(0); GETTER_SERVICE_KIND_COUNT];
+
+fn initialize_service<const K: GetterServiceKind>(srv: &mut Shared<ServiceGetterInterface<K>>) -> Result<()> {
+    if srv.is_null() {
+        *srv = service::new_service_object()?;
+
+        unsafe {
+            match srv.get_read_only_application_control_data_interface() {
+                Ok(ro_intf) => G_RO_INTFS[K as usize] = ro_intf.to(),
+                Err(rc) => G_RO_INTF_RCS[K as usize] = rc
+            };
+            match srv.get_application_manager_interface() {
+                Ok(app_man_intf) => G_APP_MAN_INTFS[K as usize] = app_man_intf.to(),
+                Err(rc) => G_APP_MAN_INTF_RCS[K as usize] = rc
+            };
         }
     }
 
     Ok(())
 }
 
+// Returns the configured kinds which are actually available, the others are just left unmitm'd
+
+pub fn initialize(kinds: &[GetterServiceKind]) -> Result<Vec<GetterServiceKind>> {
+    let mut available_kinds: Vec<GetterServiceKind> = Vec::new();
+    unsafe {
+        // ns:am2 is always needed, since nashe itself queries the real records through it
+        initialize_service(&mut G_AM2_SRV)?;
+
+        for kind in kinds {
+            let rc = match kind {
+                GetterServiceKind::AM2 => Ok(()),
+                GetterServiceKind::EC => initialize_service(&mut G_EC_SRV),
+                GetterServiceKind::RID => initialize_service(&mut G_RID_SRV),
+                GetterServiceKind::RT => initialize_service(&mut G_RT_SRV),
+                GetterServiceKind::WEB => initialize_service(&mut G_WEB_SRV),
+                GetterServiceKind::RO => initialize_service(&mut G_RO_SRV)
+            };
+            match rc {
+                Ok(()) => available_kinds.push(*kind),
+                Err(rc) => diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to open {:?}, not mitm'ing it: {}\n", kind, ResultDisplay(rc.get_value()))
+            }
+        }
+    }
+
+    Ok(available_kinds)
+}
+
 pub fn finalize() {
     unsafe {
-        if G_SRV.is_valid() {
-            G_SRV.reset();
+        for ro_intf in G_RO_INTFS.iter_mut() {
+            ro_intf.reset();
+        }
+        for app_man_intf in G_APP_MAN_INTFS.iter_mut() {
+            app_man_intf.reset();
+        }
+
+        G_AM2_SRV.reset();
+        G_EC_SRV.reset();
+        G_RID_SRV.reset();
+        G_RT_SRV.reset();
+        G_WEB_SRV.reset();
+        G_RO_SRV.reset();
+    }
+}
+
+pub fn check_read_only_application_control_data_interface(kind: GetterServiceKind) -> Result<()> {
+    unsafe {
+        if G_RO_INTFS[kind as usize].is_valid() {
+            Ok(())
+        }
+        else {
+            Err(G_RO_INTF_RCS[kind as usize])
+        }
+    }
+}
+
+pub fn check_application_manager_interface(kind: GetterServiceKind) -> Result<()> {
+    unsafe {
+        if G_APP_MAN_INTFS[kind as usize].is_valid() {
+            Ok(())
+        }
+        else {
+            Err(G_APP_MAN_INTF_RCS[kind as usize])
         }
     }
 }
 
 #[inline]
-pub fn get_read_only_application_control_data_interface() -> &'static mut Shared<ReadOnlyApplicationControlDataInterface> {
+pub fn get_read_only_application_control_data_interface(kind: GetterServiceKind) -> &'static mut Shared<ReadOnlyApplicationControlDataInterface> {
     unsafe {
-        &mut G_RO_INTF
+        &mut G_RO_INTFS[kind as usize]
     }
 }
 
 #[inline]
-pub fn get_application_manager_interface() -> &'static mut Shared<ApplicationManagerInterface> {
+pub fn get_application_manager_interface(kind: GetterServiceKind) -> &'static mut Shared<ApplicationManagerInterface> {
     unsafe {
-        &mut G_APP_MAN_INTF
+        &mut G_APP_MAN_INTFS[kind as usize]
     }
-}
\ No newline at end of file
+}
+
+const RECORD_PAGE_COUNT: usize = 0x40;
+
+pub fn list_all_application_records(kind: GetterServiceKind) -> Result<Vec<ApplicationRecord>> {
+    let mut records: Vec<ApplicationRecord> = Vec::new();
+    let page: Vec<ApplicationRecord> = vec![Default::default(); RECORD_PAGE_COUNT];
+    loop {
+        let count = core::cmp::min(get_application_manager_interface(kind).list_application_record(records.len() as u32, sf::OutMapAliasBuffer::from_array(&page))? as usize, RECORD_PAGE_COUNT);
+        records.extend_from_slice(&page[..count]);
+        if count < RECORD_PAGE_COUNT {
+            break;
+        }
+    }
+    Ok(records)
+}
@@ -0,0 +1,76 @@
+use super::*;
+use nashe::results::ResultDisplay;
+use nx::svc;
+use nx::wait;
+use nx::thread;
+use crate::hb;
+
+// Our own application record update event, handed to callers instead of the real one
+// It fires whenever the real one does, and whenever our extra applications change
+
+static mut G_UPDATE_EVENT_HANDLE: svc::Handle = svc::INVALID_HANDLE;
+static mut G_UPDATE_CLIENT_EVENT_HANDLE: svc::Handle = svc::INVALID_HANDLE;
+static mut G_REAL_UPDATE_EVENT_HANDLE: svc::Handle = svc::INVALID_HANDLE;
+static mut G_FORWARD_THREAD: Option<thread::Thread> = None;
+
+const FORWARD_THREAD_STACK_SIZE: usize = 0x2000;
+
+fn forward_real_update_event(_: &()) {
+    let real_event_handle = unsafe {
+        G_REAL_UPDATE_EVENT_HANDLE
+    };
+
+    loop {
+        if let Err(rc) = wait::wait_handles(&[real_event_handle], -1) {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Error, true } => "[nashe] Stopped forwarding the real record update event: {}\n", ResultDisplay(rc.get_value()));
+            break;
+        }
+
+        let _ = svc::reset_signal(real_event_handle);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Trace, true } => "[nashe] Real application records were updated\n");
+        // Newly installed titles might collide with extra applications, rescan right away to check that (there might be no periodic rescans)
+        hb::id::invalidate_real_application_ids();
+        hb::rescan::request_rescan();
+        signal_record_update();
+    }
+}
+
+pub fn initialize() -> Result<()> {
+    let (event_handle, client_event_handle) = svc::create_event()?;
+    unsafe {
+        G_UPDATE_EVENT_HANDLE = event_handle;
+        G_UPDATE_CLIENT_EVENT_HANDLE = client_event_handle;
+    }
+
+    // ns:am2 is always there, and the event is the same for every ns service
+    let real_event = client::get_application_manager_interface(GetterServiceKind::AM2).get_application_record_update_system_event()?;
+    unsafe {
+        G_REAL_UPDATE_EVENT_HANDLE = real_event.handle;
+    }
+
+    let mut forward_thread = thread::Thread::new(forward_real_update_event, &(), core::ptr::null_mut(), FORWARD_THREAD_STACK_SIZE, "nashe.RecordEvent")?;
+    forward_thread.create_and_start(thread::PRIORITY_AUTO, -2)?;
+    unsafe {
+        G_FORWARD_THREAD = Some(forward_thread);
+    }
+
+    Ok(())
+}
+
+pub fn signal_record_update() {
+    // Whatever changed, callers have to get a fresh list
+    mitm::invalidate_merged_records();
+    unsafe {
+        // Nothing to do before we're initialized, callers will list everything anyway
+        if G_UPDATE_EVENT_HANDLE != svc::INVALID_HANDLE {
+            let _ = svc::signal_event(G_UPDATE_EVENT_HANDLE);
+        }
+    }
+}
+
+#[inline]
+pub fn get_record_update_event_handle() -> sf::CopyHandle {
+    unsafe {
+        sf::CopyHandle::from(G_UPDATE_CLIENT_EVENT_HANDLE)
+    }
+}
@@ -0,0 +1,256 @@
+use super::*;
+use alloc::vec::Vec;
+use nx::ipc::sf;
+use nx::svc;
+use nashe::results::ResultDescription;
+
+// Server-side async objects for results nashe computes (or post-processes) by itself
+// They all share an AsyncState, which whoever does the work completes (signalling its event) once done
+
+pub struct AsyncState {
+    event_handle: svc::Handle,
+    client_event_handle: svc::Handle,
+    value: Option<Result<Vec<u8>>>
+}
+
+impl AsyncState {
+    pub fn new() -> Result<Self> {
+        let (event_handle, client_event_handle) = svc::create_event()?;
+        Ok(Self { event_handle, client_event_handle, value: None })
+    }
+
+    pub fn new_completed(value: Result<Vec<u8>>) -> Result<Self> {
+        let mut state = Self::new()?;
+        state.complete(value);
+        Ok(state)
+    }
+
+    pub fn complete(&mut self, value: Result<Vec<u8>>) {
+        if self.value.is_none() {
+            self.value = Some(value);
+            let _ = svc::signal_event(self.event_handle);
+        }
+    }
+
+    #[inline]
+    pub fn get_event_handle(&self) -> sf::CopyHandle {
+        sf::CopyHandle::from(self.client_event_handle)
+    }
+
+    fn get_value(&self) -> Result<&[u8]> {
+        match &self.value {
+            Some(Ok(value)) => Ok(value.as_slice()),
+            Some(Err(rc)) => Err(*rc),
+            None => Err(ResultCode::new(ResultDescription::AsyncNotFinished.get_value()))
+        }
+    }
+
+    fn cancel(&mut self) {
+        self.complete(Err(ResultCode::new(ResultDescription::AsyncCanceled.get_value())));
+    }
+
+    fn write_value(&self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        let value = self.get_value()?;
+        let mut out_writer = buf::OutBufferWriter::new(out_buf);
+        out_writer.write_bytes(value)
+    }
+
+}
+
+impl Drop for AsyncState {
+    fn drop(&mut self) {
+        let _ = svc::close_handle(self.client_event_handle);
+        let _ = svc::close_handle(self.event_handle);
+    }
+}
+
+// We never have any error context to provide
+
+fn write_error_context(out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+    let mut out_writer = buf::OutBufferWriter::new(out_buf);
+    out_writer.clear();
+    Ok(())
+}
+
+// Nor any progress to report, whatever we do is already completed by the time callers get the object
+
+fn write_progress(out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+    let mut out_writer = buf::OutBufferWriter::new(out_buf);
+    out_writer.clear();
+    Ok(())
+}
+
+pub struct LocalAsyncValue {
+    session: sf::Session,
+    state: Shared<AsyncState>
+}
+
+impl LocalAsyncValue {
+    pub fn new(state: Shared<AsyncState>) -> Self {
+        Self { session: sf::Session::new(), state }
+    }
+}
+
+impl sf::IObject for LocalAsyncValue {
+    fn get_session(&mut self) -> &mut sf::Session {
+        &mut self.session
+    }
+
+    fn get_command_table(&self) -> sf::CommandMetadataTable {
+        vec! [
+            ipc_cmif_interface_make_command_meta!(get_size: 0),
+            ipc_cmif_interface_make_command_meta!(get: 1),
+            ipc_cmif_interface_make_command_meta!(cancel: 2),
+            ipc_cmif_interface_make_command_meta!(get_error_context: 3)
+        ]
+    }
+}
+
+impl IAsyncValue for LocalAsyncValue {
+    fn get_size(&mut self) -> Result<usize> {
+        Ok(self.state.get_value()?.len())
+    }
+
+    fn get(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        self.state.write_value(out_buf)
+    }
+
+    fn cancel(&mut self) -> Result<()> {
+        self.state.cancel();
+        Ok(())
+    }
+
+    fn get_error_context(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        write_error_context(out_buf)
+    }
+}
+
+pub struct LocalAsyncResult {
+    session: sf::Session,
+    state: Shared<AsyncState>
+}
+
+impl LocalAsyncResult {
+    pub fn new(state: Shared<AsyncState>) -> Self {
+        Self { session: sf::Session::new(), state }
+    }
+}
+
+impl sf::IObject for LocalAsyncResult {
+    fn get_session(&mut self) -> &mut sf::Session {
+        &mut self.session
+    }
+
+    fn get_command_table(&self) -> sf::CommandMetadataTable {
+        vec! [
+            ipc_cmif_interface_make_command_meta!(get: 0),
+            ipc_cmif_interface_make_command_meta!(cancel: 1),
+            ipc_cmif_interface_make_command_meta!(get_error_context: 2)
+        ]
+    }
+}
+
+impl IAsyncResult for LocalAsyncResult {
+    fn get(&mut self) -> Result<()> {
+        self.state.get_value().map(|_| ())
+    }
+
+    fn cancel(&mut self) -> Result<()> {
+        self.state.cancel();
+        Ok(())
+    }
+
+    fn get_error_context(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        write_error_context(out_buf)
+    }
+}
+
+pub struct LocalProgressAsyncResult {
+    session: sf::Session,
+    state: Shared<AsyncState>
+}
+
+impl LocalProgressAsyncResult {
+    pub fn new(state: Shared<AsyncState>) -> Self {
+        Self { session: sf::Session::new(), state }
+    }
+}
+
+impl sf::IObject for LocalProgressAsyncResult {
+    fn get_session(&mut self) -> &mut sf::Session {
+        &mut self.session
+    }
+
+    fn get_command_table(&self) -> sf::CommandMetadataTable {
+        vec! [
+            ipc_cmif_interface_make_command_meta!(get: 0),
+            ipc_cmif_interface_make_command_meta!(cancel: 1),
+            ipc_cmif_interface_make_command_meta!(get_progress: 2),
+            ipc_cmif_interface_make_command_meta!(get_detail_result: 3),
+            ipc_cmif_interface_make_command_meta!(get_error_context: 4)
+        ]
+    }
+}
+
+impl IAsyncResult for LocalProgressAsyncResult {
+    fn get(&mut self) -> Result<()> {
+        self.state.get_value().map(|_| ())
+    }
+
+    fn cancel(&mut self) -> Result<()> {
+        self.state.cancel();
+        Ok(())
+    }
+
+    fn get_error_context(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        write_error_context(out_buf)
+    }
+}
+
+impl IProgressAsyncResult for LocalProgressAsyncResult {
+    fn get_progress(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
+        write_progress(out_buf)
+    }
+
+    fn get_detail_result(&mut self) -> Result<()> {
+        self.state.get_value().map(|_| ())
+    }
+}
+
+// Helpers for commands answering with (event, async object)
+
+pub fn make_async_value(state: Shared<AsyncState>) -> (sf::CopyHandle, Shared<dyn sf::IObject>) {
+    let event = state.get_event_handle();
+    let val: Shared<dyn sf::IObject> = Shared::new(LocalAsyncValue::new(state));
+    (event, val)
+}
+
+pub fn make_async_result(state: Shared<AsyncState>) -> (sf::CopyHandle, Shared<dyn sf::IObject>) {
+    let event = state.get_event_handle();
+    let async_rc: Shared<dyn sf::IObject> = Shared::new(LocalAsyncResult::new(state));
+    (event, async_rc)
+}
+
+pub fn make_progress_async_result(state: Shared<AsyncState>) -> (sf::CopyHandle, Shared<dyn sf::IObject>) {
+    let event = state.get_event_handle();
+    let async_rc: Shared<dyn sf::IObject> = Shared::new(LocalProgressAsyncResult::new(state));
+    (event, async_rc)
+}
+
+pub fn make_completed_async_value<T: Copy>(t: T) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
+    let t_bytes = unsafe {
+        core::slice::from_raw_parts(&t as *const T as *const u8, core::mem::size_of::<T>())
+    };
+    let state = AsyncState::new_completed(Ok(t_bytes.to_vec()))?;
+    Ok(make_async_value(Shared::new(state)))
+}
+
+pub fn make_completed_async_result(rc: Result<()>) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
+    let state = AsyncState::new_completed(rc.map(|_| Vec::new()))?;
+    Ok(make_async_result(Shared::new(state)))
+}
+
+pub fn make_completed_progress_async_result(rc: Result<()>) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
+    let state = AsyncState::new_completed(rc.map(|_| Vec::new()))?;
+    Ok(make_progress_async_result(Shared::new(state)))
+}
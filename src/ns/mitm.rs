@@ -2,17 +2,95 @@ use super::*;
 use alloc::vec::Vec;
 use nx::ipc::sf;
 use nx::service;
+use nx::svc;
+use nx::wait;
+use nx::sync;
+use nashe::results::{ResultDescription, ResultDisplay};
 use client::{AsyncResult, AsyncValue, AsyncValueAndProgress, ProgressAsyncResult, ProgressMonitorForDeleteUserSaveDataAll, ApplicationResource, RequestServerStopper, GameCardStopper};
 use crate::hb;
+use crate::overrides;
+use crate::trace::TraceInterface;
+use crate::config;
+use crate::order;
+
+// Real titles hidden through the config, which mitm'd callers don't get to see at all
+// Lookups of a single hidden ID fail, while batches just get an empty (zeroed) entry for it, as if it didn't exist
+
+fn is_hidden_application(app_id: ApplicationId) -> bool {
+    !hb::is_extra_application(app_id) && config::get().is_application_hidden(app_id)
+}
+
+fn is_hidden_application_in(extra_apps: &[hb::ExtraApplication], app_id: ApplicationId) -> bool {
+    !hb::is_extra_application_in(extra_apps, app_id) && config::get().is_application_hidden(app_id)
+}
+
+fn is_real_application_in(extra_apps: &[hb::ExtraApplication], app_id: ApplicationId) -> bool {
+    !hb::is_extra_application_in(extra_apps, app_id) && !config::get().is_application_hidden(app_id)
+}
+
+fn check_application_not_hidden(app_id: ApplicationId) -> Result<()> {
+    if is_hidden_application(app_id) {
+        Err(ResultCode::new(ResultDescription::ApplicationHidden.get_value()))
+    }
+    else {
+        Ok(())
+    }
+}
+
+// The merged (real + extra) and sorted record list, which callers page through one command at a time
+// It's rebuilt after every record update, the generation making sure a list built across an update isn't kept
+
+static mut G_MERGED_RECORDS: Option<Vec<ApplicationRecord>> = None;
+static mut G_MERGED_RECORDS_GENERATION: u64 = 0;
+static mut G_MERGED_RECORDS_LOCK: sync::Mutex = sync::Mutex::new(false);
+
+pub fn invalidate_merged_records() {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_MERGED_RECORDS_LOCK);
+        G_MERGED_RECORDS = None;
+        G_MERGED_RECORDS_GENERATION += 1;
+    }
+}
+
+fn get_merged_records(kind: GetterServiceKind, caller: CallerInfo) -> Result<Vec<ApplicationRecord>> {
+    let generation = unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_MERGED_RECORDS_LOCK);
+        if let Some(records) = G_MERGED_RECORDS.as_ref() {
+            return Ok(records.clone());
+        }
+        G_MERGED_RECORDS_GENERATION
+    };
+
+    let mut real_records = client::list_all_application_records(kind)?;
+    real_records.retain(|record| !is_hidden_application(record.app_id));
+    let hb_records = hb::get_extra_application_records();
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Records -> real: {} + hb: {}\n", caller, real_records.len(), hb_records.len());
+
+    let mut records = real_records;
+    records.extend(hb_records);
+    order::sort_records(&mut records);
+
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_MERGED_RECORDS_LOCK);
+        if G_MERGED_RECORDS_GENERATION == generation {
+            G_MERGED_RECORDS = Some(records.clone());
+        }
+    }
+    Ok(records)
+}
 
 pub struct ReadOnlyApplicationControlDataInterface {
-    session: sf::Session
+    session: sf::Session,
+    kind: GetterServiceKind,
+    caller: CallerInfo
 }
 
 impl ReadOnlyApplicationControlDataInterface {
-    pub fn new() -> Self {
+    pub fn new(kind: GetterServiceKind, caller: CallerInfo) -> Self {
         Self {
-            session: sf::Session::new()
+            session: sf::Session::new(),
+            kind,
+            caller
         }
     }
 }
@@ -35,56 +113,160 @@ impl sf::IObject for ReadOnlyApplicationControlDataInterface {
 
 impl IReadOnlyApplicationControlDataInterface for ReadOnlyApplicationControlDataInterface {
     fn get_application_control_data(&mut self, source: ApplicationControlSource, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface -> get_application_control_data [source: {:?}, app_id: {:?}]\n", source, app_id);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface [{}] -> get_application_control_data [source: {:?}, app_id: {:?}]\n", self.caller, source, app_id);
 
-        if hb::is_extra_application(app_id) {
-            let (nacp_data, icon_data) = hb::get_extra_application_control_data(app_id)?;
+        trace_command!(self.kind, self.caller, TraceInterface::ReadOnlyApplicationControlData, 0, (source, app_id, out_buf) => {
+            if hb::is_extra_application(app_id) {
+                let (nacp_data, icon_data) = hb::get_extra_application_control_data(app_id)?;
             
-            unsafe {
-                core::ptr::copy(nacp_data.as_ptr(), out_buf.buf as *mut u8, nacp_data.len());
-                core::ptr::copy(icon_data.as_ptr(), out_buf.buf.offset(core::mem::size_of::<ApplicationControlProperty>() as isize) as *mut u8, icon_data.len());
-            }
+                // The icon always follows the NACP, and neither of them is of any use truncated
+                let mut out_writer = buf::OutBufferWriter::new(out_buf);
+                out_writer.write_bytes(&nacp_data)?;
+                out_writer.write_bytes(&icon_data)?;
 
-            Ok((nacp_data.len() + icon_data.len()) as u32)
-        }
-        else {
-            client::get_read_only_application_control_data_interface().get_application_control_data(source, app_id, out_buf)
-        }
+                Ok((nacp_data.len() + icon_data.len()) as u32)
+            }
+            else {
+                check_application_not_hidden(app_id)?;
+                let size = client::get_read_only_application_control_data_interface(self.kind).get_application_control_data(source, app_id, out_buf.clone())?;
+                overrides::apply_control_data_overrides(app_id, out_buf, size as usize).map(|size| size as u32)
+            }
+        })
     }
 
     fn get_application_desired_language(&mut self, lang_bitmask: u8) -> Result<u8> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface -> get_application_desired_language\n");
-        client::get_read_only_application_control_data_interface().get_application_desired_language(lang_bitmask)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface [{}] -> get_application_desired_language\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ReadOnlyApplicationControlData, 1, (lang_bitmask) => {
+            client::get_read_only_application_control_data_interface(self.kind).get_application_desired_language(lang_bitmask)
+        })
     }
 
     fn convert_application_language_to_language_code(&mut self, app_lang: u8) -> Result<CString<0x8>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface -> convert_application_language_to_language_code\n");
-        client::get_read_only_application_control_data_interface().convert_application_language_to_language_code(app_lang)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface [{}] -> convert_application_language_to_language_code\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ReadOnlyApplicationControlData, 2, (app_lang) => {
+            client::get_read_only_application_control_data_interface(self.kind).convert_application_language_to_language_code(app_lang)
+        })
     }
 
     fn convert_language_code_to_application_language(&mut self, lang_code: CString<0x8>) -> Result<u8> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface -> convert_language_code_to_application_language\n");
-        client::get_read_only_application_control_data_interface().convert_language_code_to_application_language(lang_code)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface [{}] -> convert_language_code_to_application_language\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ReadOnlyApplicationControlData, 3, (lang_code) => {
+            client::get_read_only_application_control_data_interface(self.kind).convert_language_code_to_application_language(lang_code)
+        })
     }
 
     fn select_application_desired_language(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface -> select_application_desired_language\n");
-        todo!("select_application_desired_language")
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IReadOnlyApplicationControlDataInterface [{}] -> select_application_desired_language\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ReadOnlyApplicationControlData, 4, () => {
+            client::get_read_only_application_control_data_interface(self.kind).select_application_desired_language()
+        })
     }
 }
 
 pub struct ApplicationManagerInterface {
-    session: sf::Session
+    session: sf::Session,
+    kind: GetterServiceKind,
+    caller: CallerInfo
 }
 
 impl ApplicationManagerInterface {
-    pub fn new() -> Self {
+    pub fn new(kind: GetterServiceKind, caller: CallerInfo) -> Self {
         Self {
-            session: sf::Session::new()
+            session: sf::Session::new(),
+            kind,
+            caller
+        }
+    }
+
+    // Title/icon batches: real IDs go through the real service (with our own transfer memory), extra ones are generated here
+    // Either way, the output is an array of fixed-size entries (in request order) at the offset the async value holds
+
+    fn list_application_control_entries(&mut self, is_icon: bool, app_id_buf: sf::InMapAliasBuffer, source: ApplicationControlSource, tmem_handle: sf::CopyHandle, tmem_size: usize, entry_size: usize, gen_extra_entry: fn(ApplicationId, &mut [u8]) -> Result<()>) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
+        let app_ids = app_id_buf.get_slice::<ApplicationId>();
+        let extra_apps = hb::get_extra_applications();
+        if app_ids.iter().all(|app_id| is_real_application_in(&extra_apps, *app_id)) {
+            if is_icon {
+                return client::get_application_manager_interface(self.kind).list_application_icon(app_id_buf, source, tmem_handle, tmem_size);
+            }
+            else {
+                return client::get_application_manager_interface(self.kind).list_application_title(app_id_buf, source, tmem_handle, tmem_size);
+            }
+        }
+
+        let mut out_tmem = tmem::MappedTransferMemory::map(tmem_handle, tmem_size)?;
+        let out_entries = out_tmem.get_mut_slice().get_mut(..app_ids.len() * entry_size).ok_or(ResultCode::new(ResultDescription::BufferTooSmall.get_value()))?;
+        out_entries.fill(0);
+
+        // Real IDs are forwarded a few at a time, each chunk through its own small buffer (icons alone are almost 128KB each)
+        let real_idxs: Vec<usize> = (0..app_ids.len()).filter(|idx| is_real_application_in(&extra_apps, app_ids[*idx])).collect();
+        let chunk_len = core::cmp::max(1, REAL_CONTROL_ENTRIES_BUFFER_SIZE / entry_size);
+        for chunk_idxs in real_idxs.chunks(chunk_len) {
+            let real_tmem = match tmem::OwnedTransferMemory::new(chunk_idxs.len() * entry_size) {
+                Ok(real_tmem) => real_tmem,
+                Err(rc) => {
+                    // Extra entries are still worth returning, these real ones are just left empty
+                    diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "mitm:IApplicationManagerInterface [{}] -> Unable to allocate memory for real control entries, leaving them empty: {}\n", self.caller, ResultDisplay(rc.get_value()));
+                    continue;
+                }
+            };
+
+            let chunk_app_ids: Vec<ApplicationId> = chunk_idxs.iter().map(|idx| app_ids[*idx]).collect();
+            let real_entries = list_real_control_entries(self.kind, is_icon, &chunk_app_ids, source, real_tmem, entry_size)?;
+            for (idx, real_entry) in chunk_idxs.iter().zip(real_entries.chunks_exact(entry_size)) {
+                out_entries[idx * entry_size..(idx + 1) * entry_size].copy_from_slice(real_entry);
+            }
         }
+
+        // Hidden entries are just left empty
+        let mut hb_count: usize = 0;
+        for (app_id, out_entry) in app_ids.iter().zip(out_entries.chunks_exact_mut(entry_size)) {
+            if hb::is_extra_application_in(&extra_apps, *app_id) {
+                gen_extra_entry(*app_id, out_entry)?;
+                hb_count += 1;
+            }
+        }
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Control entries -> real: {} + hb: {} (hidden: {})\n", self.caller, real_idxs.len(), hb_count, app_ids.len() - real_idxs.len() - hb_count);
+
+        // Everything is written from the start of the caller's transfer memory
+        local::make_completed_async_value(0u64)
     }
 }
 
+// Enough for a single icon entry, or plenty of title ones
+const REAL_CONTROL_ENTRIES_BUFFER_SIZE: usize = 0x21000;
+
+// The real service might be reading a slow gamecard, but this runs on our only server thread
+const REAL_CONTROL_ENTRIES_TIMEOUT: i64 = 10_000_000_000;
+
+// Our own pages can't be accessed while they're transfer memory, so they're only read back once the real service let go of them and the handle is closed
+
+fn list_real_control_entries(kind: GetterServiceKind, is_icon: bool, app_ids: &[ApplicationId], source: ApplicationControlSource, mut real_tmem: tmem::OwnedTransferMemory, entry_size: usize) -> Result<Vec<u8>> {
+    let (real_event, mut real_value) = if is_icon {
+        client::get_application_manager_interface(kind).list_application_icon_value(sf::InMapAliasBuffer::from_array(app_ids), source, real_tmem.get_handle(), real_tmem.get_size())?
+    }
+    else {
+        client::get_application_manager_interface(kind).list_application_title_value(sf::InMapAliasBuffer::from_array(app_ids), source, real_tmem.get_handle(), real_tmem.get_size())?
+    };
+    let wait_rc = wait::wait_handles(&[real_event.handle], REAL_CONTROL_ENTRIES_TIMEOUT);
+    let _ = svc::close_handle(real_event.handle);
+    if let Err(rc) = wait_rc {
+        // The real service might still be writing to it, so it's leaked rather than freed
+        let _ = IAsyncValue::cancel(&mut *real_value);
+        drop(real_value);
+        core::mem::forget(real_tmem);
+        return Err(rc);
+    }
+
+    let real_offset: [u64; 1] = [0];
+    let get_rc = IAsyncValue::get(&mut *real_value, sf::OutMapAliasBuffer::from_array(&real_offset));
+    drop(real_value);
+    get_rc?;
+
+    let real_start = real_offset[0] as usize;
+    let real_data = real_tmem.close_and_get_slice().get(real_start..real_start + app_ids.len() * entry_size).ok_or(ResultCode::new(ResultDescription::BufferTooSmall.get_value()))?;
+    Ok(real_data.to_vec())
+}
+
 impl sf::IObject for ApplicationManagerInterface {
     fn get_session(&mut self) -> &mut sf::Session {
         &mut self.session
@@ -373,1516 +555,2172 @@ impl sf::IObject for ApplicationManagerInterface {
 
 impl IApplicationManagerInterface for ApplicationManagerInterface {
     fn list_application_record(&mut self, entry_offset: u32, out_record_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_record\n");
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> buf count: {}\n", out_record_buf.size / core::mem::size_of::<ApplicationRecord>());
-
-        assert_eq!(entry_offset, 0);
-
-        let orb = out_record_buf.clone();
-        let out_record_buf_buf = out_record_buf.buf;
-        let real_record_count = client::get_application_manager_interface().list_application_record(entry_offset, out_record_buf)?;
-        let hb_records = hb::get_extra_application_records();
-        let hb_record_count = hb_records.len();
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> Records -> real: {} + hb: {}\n", real_record_count, hb_record_count);
-
-        let out_hb_record_buf = unsafe {
-            sf::OutMapAliasBuffer::from_const(out_record_buf_buf.offset(real_record_count as isize * core::mem::size_of::<ApplicationRecord>() as isize), hb_record_count * core::mem::size_of::<ApplicationRecord>())
-        };
-        let out_hb_records = out_hb_record_buf.get_mut_slice::<ApplicationRecord>();
-        for i in 0..hb_record_count {
-            out_hb_records[i] = hb_records[i];
-        }
-
-        let orba = orb.get_slice::<ApplicationRecord>();
-        for i in 0..(real_record_count as usize + hb_record_count) {
-            diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> Record: {:?}\n", orba[i]);
-        }
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 0, (entry_offset, out_record_buf) => {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> buf count: {}\n", self.caller, out_record_buf.size / core::mem::size_of::<ApplicationRecord>());
+
+            // Real records and extra ones are merged and sorted, then paged through as a single list
+            let records = get_merged_records(self.kind, self.caller)?;
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Records: {}, offset: {}\n", self.caller, records.len(), entry_offset);
+
+            // Whatever doesn't fit in the caller's buffer is left for its next page
+            let mut out_writer = buf::OutBufferWriter::new(out_record_buf);
+            let count = out_writer.write_truncated(records.iter().skip(entry_offset as usize));
+            for record in records.iter().skip(entry_offset as usize).take(count) {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Record: {:?}\n", self.caller, record);
+            }
 
-        Ok(real_record_count + hb_record_count as u32)
+            Ok(count as u32)
+        })
     }
 
     fn generate_application_record_count(&mut self) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> generate_application_record_count\n");
-        
-        let real_record_count = client::get_application_manager_interface().generate_application_record_count()?;
-        let hb_record_count = hb::get_extra_application_records().len() as u64;
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> Records -> real: {} + hb: {}\n", real_record_count, hb_record_count);
-        Ok(real_record_count + hb_record_count)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> generate_application_record_count\n", self.caller);
+
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1, () => {
+            // Same list callers page through, so hidden titles are already counted out
+            let record_count = get_merged_records(self.kind, self.caller)?.len() as u64;
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Records: {}\n", self.caller, record_count);
+            Ok(record_count)
+        })
     }
 
     fn get_application_record_update_system_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_record_update_system_event\n");
-        client::get_application_manager_interface().get_application_record_update_system_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_record_update_system_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2, () => {
+            // Our own event, which also fires when extra applications change
+            Ok(event::get_record_update_event_handle())
+        })
     }
 
     fn get_application_view_deprecated(&mut self, in_app_ids: sf::InMapAliasBuffer, out_views: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_view_deprecated\n");
-
-        let app_ids = in_app_ids.get_slice::<ApplicationId>();
-        let mut real_app_ids = app_ids.to_vec();
-        real_app_ids.retain(|app_id| !hb::is_extra_application(*app_id));
-
-        let mut out_real_views: Vec<ApplicationViewDeprecated> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
-        client::get_application_manager_interface().get_application_view_deprecated(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
-
-        let out_views_arr = out_views.get_mut_slice::<ApplicationViewDeprecated>();
-        let mut i: usize = 0;
-        let mut j: usize = 0;
-        for app_id in app_ids {
-            if hb::is_extra_application(*app_id) {
-                out_views_arr[i] = hb::gen_deprecated_application_view(*app_id);
-            }
-            else {
-                out_views_arr[i] = out_real_views[j];
-                j += 1;
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_view_deprecated\n", self.caller);
+
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3, (in_app_ids, out_views) => {
+            let app_ids = in_app_ids.get_slice::<ApplicationId>();
+            let extra_apps = hb::get_extra_applications();
+            let mut real_app_ids = app_ids.to_vec();
+            real_app_ids.retain(|app_id| is_real_application_in(&extra_apps, *app_id));
+
+            let mut out_real_views: Vec<ApplicationViewDeprecated> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
+            client::get_application_manager_interface(self.kind).get_application_view_deprecated(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
+
+            let mut out_writer = buf::OutBufferWriter::new(out_views);
+            let mut j: usize = 0;
+            for app_id in app_ids {
+                if hb::is_extra_application_in(&extra_apps, *app_id) {
+                    out_writer.write(&hb::gen_deprecated_application_view(*app_id))?;
+                }
+                else if is_hidden_application_in(&extra_apps, *app_id) {
+                    out_writer.write(&unsafe { core::mem::zeroed::<ApplicationViewDeprecated>() })?;
+                }
+                else {
+                    out_writer.write(out_real_views.get(j).ok_or(ResultCode::new(ResultDescription::BufferTooSmall.get_value()))?)?;
+                    j += 1;
+                }
             }
-            i += 1;
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn delete_application_entity(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_entity\n");
-        client::get_application_manager_interface().delete_application_entity(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 4, (app_id) => {
+            client::get_application_manager_interface(self.kind).delete_application_entity(app_id)
+        })
     }
 
     fn delete_application_completely(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_completely\n");
-        client::get_application_manager_interface().delete_application_completely(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_completely\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 5, (app_id) => {
+            client::get_application_manager_interface(self.kind).delete_application_completely(app_id)
+        })
     }
 
     fn is_any_application_entity_redundant(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_any_application_entity_redundant\n");
-        client::get_application_manager_interface().is_any_application_entity_redundant()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_any_application_entity_redundant\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 6, () => {
+            client::get_application_manager_interface(self.kind).is_any_application_entity_redundant()
+        })
     }
 
     fn delete_redundant_application_entity(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_redundant_application_entity\n");
-        client::get_application_manager_interface().delete_redundant_application_entity()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_redundant_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 7, () => {
+            client::get_application_manager_interface(self.kind).delete_redundant_application_entity()
+        })
     }
 
     fn is_application_entity_movable(&mut self, storage_id: StorageId, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_redundant_application_entity\n");
-        client::get_application_manager_interface().is_application_entity_movable(storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_redundant_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 8, (storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).is_application_entity_movable(storage_id, app_id)
+        })
     }
 
     fn move_application_entity(&mut self, storage_id: StorageId, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> move_application_entity\n");
-        client::get_application_manager_interface().move_application_entity(storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> move_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 9, (storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).move_application_entity(storage_id, app_id)
+        })
     }
 
     fn calculate_application_occupied_size(&mut self, app_id: ApplicationId) -> Result<ApplicationOccupiedSize> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> calculate_application_occupied_size\n");
-        client::get_application_manager_interface().calculate_application_occupied_size(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> calculate_application_occupied_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 11, (app_id) => {
+            client::get_application_manager_interface(self.kind).calculate_application_occupied_size(app_id)
+        })
     }
 
     fn push_application_record(&mut self, last_modified_event: u8, app_id: ApplicationId, record_buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> push_application_record\n");
-        client::get_application_manager_interface().push_application_record(last_modified_event, app_id, record_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> push_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 16, (last_modified_event, app_id, record_buf) => {
+            client::get_application_manager_interface(self.kind).push_application_record(last_modified_event, app_id, record_buf)
+        })
     }
 
     fn list_application_record_content_meta(&mut self, offset: u64, app_id: ApplicationId, out_meta_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_record_content_meta\n");
-        client::get_application_manager_interface().list_application_record_content_meta(offset, app_id, out_meta_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_record_content_meta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 17, (offset, app_id, out_meta_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_record_content_meta(offset, app_id, out_meta_buf)
+        })
     }
 
     fn launch_application_old(&mut self, app_id: ApplicationId) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_application_old\n");
-        client::get_application_manager_interface().launch_application_old(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_application_old\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 19, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                let host_app_id = hb::launch::prepare_launch(app_id)?;
+                let process_id = client::get_application_manager_interface(self.kind).launch_application_old(host_app_id).map_err(|rc| {
+                    hb::launch::cancel_launch();
+                    rc
+                })?;
+                order::notify_extra_application_launch(app_id);
+                Ok(process_id)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).launch_application_old(app_id)
+            }
+        })
     }
 
     fn get_application_content_path(&mut self, meta_type: ContentMetaType, app_id: ApplicationId, out_path: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_content_path\n");
-        client::get_application_manager_interface().get_application_content_path(meta_type, app_id, out_path)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_content_path\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 21, (meta_type, app_id, out_path) => {
+            client::get_application_manager_interface(self.kind).get_application_content_path(meta_type, app_id, out_path)
+        })
     }
 
     fn terminate_application(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> terminate_application\n");
-        client::get_application_manager_interface().terminate_application(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> terminate_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 22, (app_id) => {
+            client::get_application_manager_interface(self.kind).terminate_application(app_id)
+        })
     }
 
     fn resolve_application_content_path(&mut self, meta_type: ContentMetaType, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> resolve_application_content_path\n");
-        client::get_application_manager_interface().resolve_application_content_path(meta_type, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> resolve_application_content_path\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 23, (meta_type, app_id) => {
+            client::get_application_manager_interface(self.kind).resolve_application_content_path(meta_type, app_id)
+        })
     }
 
     fn begin_install_application(&mut self, storage_id: StorageId, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> begin_install_application\n");
-        client::get_application_manager_interface().begin_install_application(storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> begin_install_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 26, (storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).begin_install_application(storage_id, app_id)
+        })
     }
 
     fn delete_application_record(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_record\n");
-        client::get_application_manager_interface().delete_application_record(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 27, (app_id) => {
+            client::get_application_manager_interface(self.kind).delete_application_record(app_id)
+        })
     }
 
     fn request_application_update_info(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_application_update_info\n");
-        client::get_application_manager_interface().request_application_update_info(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_application_update_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 30, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_application_update_info(app_id)
+        })
     }
 
     fn request_update_application(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_update_application\n");
-        client::get_application_manager_interface().request_update_application(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_update_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 31, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_update_application(app_id)
+        })
     }
 
     fn cancel_application_download(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cancel_application_download\n");
-        client::get_application_manager_interface().cancel_application_download(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cancel_application_download\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 32, (app_id) => {
+            client::get_application_manager_interface(self.kind).cancel_application_download(app_id)
+        })
     }
 
     fn resume_application_download(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> resume_application_download\n");
-        client::get_application_manager_interface().resume_application_download(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> resume_application_download\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 33, (app_id) => {
+            client::get_application_manager_interface(self.kind).resume_application_download(app_id)
+        })
     }
 
     fn update_version_list(&mut self, buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> update_version_list\n");
-        client::get_application_manager_interface().update_version_list(buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> update_version_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 35, (buf) => {
+            client::get_application_manager_interface(self.kind).update_version_list(buf)
+        })
     }
 
     fn push_launch_version(&mut self, version: u32, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> push_launch_version\n");
-        client::get_application_manager_interface().push_launch_version(version, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> push_launch_version\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 36, (version, app_id) => {
+            client::get_application_manager_interface(self.kind).push_launch_version(version, app_id)
+        })
     }
 
     fn list_required_version(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_required_version\n");
-        client::get_application_manager_interface().list_required_version(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_required_version\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 37, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_required_version(out_buf)
+        })
     }
 
     fn check_application_launch_version(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> check_application_launch_version\n");
-        client::get_application_manager_interface().check_application_launch_version(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> check_application_launch_version\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 38, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                hb::check_extra_application_launch(app_id)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).check_application_launch_version(app_id)
+            }
+        })
     }
 
     fn check_application_launch_rights(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> check_application_launch_rights\n");
-        client::get_application_manager_interface().check_application_launch_rights(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> check_application_launch_rights\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 39, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                hb::check_extra_application_launch(app_id)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).check_application_launch_rights(app_id)
+            }
+        })
     }
 
     fn get_application_logo_data(&mut self, app_id: ApplicationId, buf: sf::InMapAliasBuffer, out_buf: sf::OutMapAliasBuffer) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_logo_data\n");
-        client::get_application_manager_interface().get_application_logo_data(app_id, buf, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_logo_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 40, (app_id, buf, out_buf) => {
+            client::get_application_manager_interface(self.kind).get_application_logo_data(app_id, buf, out_buf)
+        })
     }
 
     fn calculate_application_download_required_size(&mut self, app_id: ApplicationId) -> Result<(u64, u64)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> calculate_application_download_required_size\n");
-        client::get_application_manager_interface().calculate_application_download_required_size(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> calculate_application_download_required_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 41, (app_id) => {
+            client::get_application_manager_interface(self.kind).calculate_application_download_required_size(app_id)
+        })
     }
 
     fn cleanup_sd_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_sd_card\n");
-        client::get_application_manager_interface().cleanup_sd_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_sd_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 42, () => {
+            client::get_application_manager_interface(self.kind).cleanup_sd_card()
+        })
     }
 
     fn check_sd_card_mount_status(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> check_sd_card_mount_status\n");
-        client::get_application_manager_interface().check_sd_card_mount_status()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> check_sd_card_mount_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 43, () => {
+            client::get_application_manager_interface(self.kind).check_sd_card_mount_status()
+        })
     }
 
     fn get_sd_card_mount_status_changed_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_sd_card_mount_status_changed_event\n");
-        client::get_application_manager_interface().get_sd_card_mount_status_changed_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_sd_card_mount_status_changed_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 44, () => {
+            client::get_application_manager_interface(self.kind).get_sd_card_mount_status_changed_event()
+        })
     }
 
     fn get_game_card_attachment_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_attachment_event\n");
-        client::get_application_manager_interface().get_game_card_attachment_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_attachment_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 45, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_attachment_event()
+        })
     }
 
     fn get_game_card_attachment_info(&mut self) -> Result<(u64, u64)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_attachment_info\n");
-        client::get_application_manager_interface().get_game_card_attachment_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_attachment_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 46, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_attachment_info()
+        })
     }
 
     fn get_total_space_size(&mut self, storage_id: StorageId) -> Result<usize> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_total_space_size\n");
-        client::get_application_manager_interface().get_total_space_size(storage_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_total_space_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 47, (storage_id) => {
+            client::get_application_manager_interface(self.kind).get_total_space_size(storage_id)
+        })
     }
 
     fn get_free_space_size(&mut self, storage_id: StorageId) -> Result<usize> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_free_space_size\n");
-        client::get_application_manager_interface().get_free_space_size(storage_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_free_space_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 48, (storage_id) => {
+            client::get_application_manager_interface(self.kind).get_free_space_size(storage_id)
+        })
     }
 
     fn get_sd_card_removed_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_sd_card_removed_event\n");
-        client::get_application_manager_interface().get_sd_card_removed_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_sd_card_removed_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 49, () => {
+            client::get_application_manager_interface(self.kind).get_sd_card_removed_event()
+        })
     }
 
     fn get_game_card_update_detection_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_update_detection_event\n");
-        client::get_application_manager_interface().get_game_card_update_detection_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_update_detection_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 52, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_update_detection_event()
+        })
     }
 
     fn disable_application_auto_delete(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> disable_application_auto_delete\n");
-        client::get_application_manager_interface().disable_application_auto_delete(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> disable_application_auto_delete\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 53, (app_id) => {
+            client::get_application_manager_interface(self.kind).disable_application_auto_delete(app_id)
+        })
     }
 
     fn enable_application_auto_delete(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_application_auto_delete\n");
-        client::get_application_manager_interface().enable_application_auto_delete(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_application_auto_delete\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 54, (app_id) => {
+            client::get_application_manager_interface(self.kind).enable_application_auto_delete(app_id)
+        })
     }
 
     fn get_application_desired_language(&mut self, lang_bitmask: u8) -> Result<u8> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_desired_language\n");
-        client::get_application_manager_interface().get_application_desired_language(lang_bitmask)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_desired_language\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 55, (lang_bitmask) => {
+            client::get_application_manager_interface(self.kind).get_application_desired_language(lang_bitmask)
+        })
     }
 
     fn set_application_terminate_result(&mut self, rc: ResultCode, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> set_application_terminate_result\n");
-        client::get_application_manager_interface().set_application_terminate_result(rc, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> set_application_terminate_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 56, (rc, app_id) => {
+            client::get_application_manager_interface(self.kind).set_application_terminate_result(rc, app_id)
+        })
     }
 
     fn clear_application_terminate_result(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> clear_application_terminate_result\n");
-        client::get_application_manager_interface().clear_application_terminate_result(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> clear_application_terminate_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 57, (app_id) => {
+            client::get_application_manager_interface(self.kind).clear_application_terminate_result(app_id)
+        })
     }
 
     fn get_last_sd_card_mount_unexpected_result(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_last_sd_card_mount_unexpected_result\n");
-        client::get_application_manager_interface().get_last_sd_card_mount_unexpected_result()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_last_sd_card_mount_unexpected_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 58, () => {
+            client::get_application_manager_interface(self.kind).get_last_sd_card_mount_unexpected_result()
+        })
     }
 
     fn convert_application_language_to_language_code(&mut self, app_lang: u8) -> Result<CString<0x8>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> convert_application_language_to_language_code\n");
-        client::get_application_manager_interface().convert_application_language_to_language_code(app_lang)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> convert_application_language_to_language_code\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 59, (app_lang) => {
+            client::get_application_manager_interface(self.kind).convert_application_language_to_language_code(app_lang)
+        })
     }
 
     fn convert_language_code_to_application_language(&mut self, lang_code: CString<0x8>) -> Result<u8> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> convert_language_code_to_application_language\n");
-        client::get_application_manager_interface().convert_language_code_to_application_language(lang_code)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> convert_language_code_to_application_language\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 60, (lang_code) => {
+            client::get_application_manager_interface(self.kind).convert_language_code_to_application_language(lang_code)
+        })
     }
 
     fn get_background_download_stress_task_info(&mut self) -> Result<(u64, u64)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_background_download_stress_task_info\n");
-        client::get_application_manager_interface().get_background_download_stress_task_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_background_download_stress_task_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 61, () => {
+            client::get_application_manager_interface(self.kind).get_background_download_stress_task_info()
+        })
     }
 
     fn get_game_card_stopper(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_stopper\n");
-        client::get_application_manager_interface().get_game_card_stopper()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_stopper\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 62, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_stopper()
+        })
     }
 
     fn is_system_program_installed(&mut self, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_system_program_installed\n");
-        client::get_application_manager_interface().is_system_program_installed(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_system_program_installed\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 63, (app_id) => {
+            client::get_application_manager_interface(self.kind).is_system_program_installed(app_id)
+        })
     }
 
     fn start_apply_delta_task(&mut self, unk_app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> start_apply_delta_task\n");
-        client::get_application_manager_interface().start_apply_delta_task(unk_app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> start_apply_delta_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 64, (unk_app_id) => {
+            client::get_application_manager_interface(self.kind).start_apply_delta_task(unk_app_id)
+        })
     }
 
     fn get_request_server_stopper(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_request_server_stopper\n");
-        client::get_application_manager_interface().get_request_server_stopper()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_request_server_stopper\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 65, () => {
+            client::get_application_manager_interface(self.kind).get_request_server_stopper()
+        })
     }
 
     fn get_background_apply_delta_stress_task_info(&mut self) -> Result<(u64, u64)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_background_apply_delta_stress_task_info\n");
-        client::get_application_manager_interface().get_background_apply_delta_stress_task_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_background_apply_delta_stress_task_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 66, () => {
+            client::get_application_manager_interface(self.kind).get_background_apply_delta_stress_task_info()
+        })
     }
 
     fn cancel_application_apply_delta(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cancel_application_apply_delta\n");
-        client::get_application_manager_interface().cancel_application_apply_delta(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cancel_application_apply_delta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 67, (app_id) => {
+            client::get_application_manager_interface(self.kind).cancel_application_apply_delta(app_id)
+        })
     }
 
     fn resume_application_apply_delta(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> resume_application_apply_delta\n");
-        client::get_application_manager_interface().resume_application_apply_delta(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> resume_application_apply_delta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 68, (app_id) => {
+            client::get_application_manager_interface(self.kind).resume_application_apply_delta(app_id)
+        })
     }
 
     fn calculate_application_apply_delta_required_size(&mut self, storage_id: StorageId, app_id: ApplicationId) -> Result<usize> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> calculate_application_apply_delta_required_size\n");
-        client::get_application_manager_interface().calculate_application_apply_delta_required_size(storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> calculate_application_apply_delta_required_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 69, (storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).calculate_application_apply_delta_required_size(storage_id, app_id)
+        })
     }
 
     fn resume_all(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> resume_all\n");
-        client::get_application_manager_interface().resume_all()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> resume_all\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 70, () => {
+            client::get_application_manager_interface(self.kind).resume_all()
+        })
     }
 
     fn get_storage_size(&mut self, storage_id: StorageId) -> Result<(usize, usize)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_storage_size\n");
-        client::get_application_manager_interface().get_storage_size(storage_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_storage_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 71, (storage_id) => {
+            client::get_application_manager_interface(self.kind).get_storage_size(storage_id)
+        })
     }
 
     fn request_download_application(&mut self, unk_storage_id: StorageId, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_application\n");
-        client::get_application_manager_interface().request_download_application(unk_storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 80, (unk_storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).request_download_application(unk_storage_id, app_id)
+        })
     }
 
     fn request_download_add_on_content(&mut self, unk_storage_id: StorageId, app_id: ApplicationId, buf: sf::InMapAliasBuffer) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_add_on_content\n");
-        client::get_application_manager_interface().request_download_add_on_content(unk_storage_id, app_id, buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_add_on_content\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 81, (unk_storage_id, app_id, buf) => {
+            client::get_application_manager_interface(self.kind).request_download_add_on_content(unk_storage_id, app_id, buf)
+        })
     }
 
     fn download_application(&mut self, unk_storage_id: StorageId, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> download_application\n");
-        client::get_application_manager_interface().download_application(unk_storage_id, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> download_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 82, (unk_storage_id, app_id) => {
+            client::get_application_manager_interface(self.kind).download_application(unk_storage_id, app_id)
+        })
     }
 
     fn check_application_resume_rights(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> check_application_resume_rights\n");
-        client::get_application_manager_interface().check_application_resume_rights(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> check_application_resume_rights\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 83, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                hb::check_extra_application_launch(app_id)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).check_application_resume_rights(app_id)
+            }
+        })
     }
 
     fn get_dynamic_commit_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_dynamic_commit_event\n");
-        client::get_application_manager_interface().get_dynamic_commit_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_dynamic_commit_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 84, () => {
+            client::get_application_manager_interface(self.kind).get_dynamic_commit_event()
+        })
     }
 
     fn request_update_application_2(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_update_application_2\n");
-        client::get_application_manager_interface().request_update_application_2(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_update_application_2\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 85, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_update_application_2(app_id)
+        })
     }
 
     fn enable_application_crash_report(&mut self, unk_enable: bool) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_application_crash_report\n");
-        client::get_application_manager_interface().enable_application_crash_report(unk_enable)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_application_crash_report\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 86, (unk_enable) => {
+            client::get_application_manager_interface(self.kind).enable_application_crash_report(unk_enable)
+        })
     }
 
     fn is_application_crash_report_enabled(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_application_crash_report_enabled\n");
-        client::get_application_manager_interface().is_application_crash_report_enabled()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_application_crash_report_enabled\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 87, () => {
+            client::get_application_manager_interface(self.kind).is_application_crash_report_enabled()
+        })
     }
 
     fn boost_system_memory_resource_limit(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> boost_system_memory_resource_limit\n");
-        client::get_application_manager_interface().boost_system_memory_resource_limit(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> boost_system_memory_resource_limit\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 90, (app_id) => {
+            client::get_application_manager_interface(self.kind).boost_system_memory_resource_limit(app_id)
+        })
     }
 
     fn deprecated_launch_application(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> deprecated_launch_application\n");
-        client::get_application_manager_interface().deprecated_launch_application()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> deprecated_launch_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 91, () => {
+            client::get_application_manager_interface(self.kind).deprecated_launch_application()
+        })
     }
 
     fn get_running_application_program_id(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_running_application_program_id\n");
-        client::get_application_manager_interface().get_running_application_program_id()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_running_application_program_id\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 92, () => {
+            client::get_application_manager_interface(self.kind).get_running_application_program_id()
+        })
     }
 
     fn get_main_application_program_index(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_main_application_program_index\n");
-        client::get_application_manager_interface().get_main_application_program_index()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_main_application_program_index\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 93, () => {
+            client::get_application_manager_interface(self.kind).get_main_application_program_index()
+        })
     }
 
     fn launch_application(&mut self, program_idx: u8, info: ApplicationLaunchInfo) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_application\n");
-        client::get_application_manager_interface().launch_application(program_idx, info)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 94, (program_idx, info) => {
+            if hb::is_extra_application(info.app_id) {
+                // The host title is launched as itself, with its own launch info
+                let host_app_id = hb::launch::prepare_launch(info.app_id)?;
+                let launch_rc = client::get_application_manager_interface(self.kind).get_application_launch_info(host_app_id).and_then(|host_info| client::get_application_manager_interface(self.kind).launch_application(0, host_info));
+                let process_id = launch_rc.map_err(|rc| {
+                    hb::launch::cancel_launch();
+                    rc
+                })?;
+                order::notify_extra_application_launch(info.app_id);
+                Ok(process_id)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).launch_application(program_idx, info)
+            }
+        })
     }
 
     fn get_application_launch_info(&mut self, app_id: ApplicationId) -> Result<ApplicationLaunchInfo> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_launch_info\n");
-        client::get_application_manager_interface().get_application_launch_info(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_launch_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 95, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                Ok(hb::gen_application_launch_info(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).get_application_launch_info(app_id)
+            }
+        })
     }
 
     fn acquire_application_launch_info(&mut self, app_id: ApplicationId) -> Result<ApplicationLaunchInfo> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> acquire_application_launch_info\n");
-        client::get_application_manager_interface().acquire_application_launch_info(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> acquire_application_launch_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 96, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                Ok(hb::gen_application_launch_info(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).acquire_application_launch_info(app_id)
+            }
+        })
     }
 
     fn get_main_application_program_index_by_application_launch_info(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_main_application_program_index_by_application_launch_info\n");
-        client::get_application_manager_interface().get_main_application_program_index_by_application_launch_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_main_application_program_index_by_application_launch_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 97, () => {
+            client::get_application_manager_interface(self.kind).get_main_application_program_index_by_application_launch_info()
+        })
     }
 
     fn enable_application_all_thread_dump_on_crash(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_application_all_thread_dump_on_crash\n");
-        client::get_application_manager_interface().enable_application_all_thread_dump_on_crash()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_application_all_thread_dump_on_crash\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 98, () => {
+            client::get_application_manager_interface(self.kind).enable_application_all_thread_dump_on_crash()
+        })
     }
 
     fn launch_dev_menu(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_dev_menu\n");
-        client::get_application_manager_interface().launch_dev_menu()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_dev_menu\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 99, () => {
+            client::get_application_manager_interface(self.kind).launch_dev_menu()
+        })
     }
 
     fn reset_to_factory_settings(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_to_factory_settings\n");
-        client::get_application_manager_interface().reset_to_factory_settings()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_to_factory_settings\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 100, () => {
+            client::get_application_manager_interface(self.kind).reset_to_factory_settings()
+        })
     }
 
     fn reset_to_factory_settings_without_user_save_data(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_to_factory_settings_without_user_save_data\n");
-        client::get_application_manager_interface().reset_to_factory_settings_without_user_save_data()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_to_factory_settings_without_user_save_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 101, () => {
+            client::get_application_manager_interface(self.kind).reset_to_factory_settings_without_user_save_data()
+        })
     }
 
     fn reset_to_factory_settings_for_refurbishment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_to_factory_settings_for_refurbishment\n");
-        client::get_application_manager_interface().reset_to_factory_settings_for_refurbishment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_to_factory_settings_for_refurbishment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 102, () => {
+            client::get_application_manager_interface(self.kind).reset_to_factory_settings_for_refurbishment()
+        })
     }
 
     fn reset_to_factory_settings_with_platform_region(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_to_factory_settings_with_platform_region\n");
-        client::get_application_manager_interface().reset_to_factory_settings_with_platform_region()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_to_factory_settings_with_platform_region\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 103, () => {
+            client::get_application_manager_interface(self.kind).reset_to_factory_settings_with_platform_region()
+        })
     }
 
     fn reset_to_factory_settings_with_platform_region_authentication(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_to_factory_settings_with_platform_region_authentication\n");
-        client::get_application_manager_interface().reset_to_factory_settings_with_platform_region_authentication()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_to_factory_settings_with_platform_region_authentication\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 104, () => {
+            client::get_application_manager_interface(self.kind).reset_to_factory_settings_with_platform_region_authentication()
+        })
     }
 
     fn request_reset_to_factory_settings_securely(&mut self, unk: u64, unk_2: sf::CopyHandle) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_reset_to_factory_settings_securely\n");
-        client::get_application_manager_interface().request_reset_to_factory_settings_securely(unk, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_reset_to_factory_settings_securely\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 105, (unk, unk_2) => {
+            client::get_application_manager_interface(self.kind).request_reset_to_factory_settings_securely(unk, unk_2)
+        })
     }
 
     fn request_reset_to_factory_settings_with_platform_region_authentication_securely(&mut self, unk: u64, unk_2: u64, unk_3: sf::CopyHandle) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_reset_to_factory_settings_with_platform_region_authentication_securely\n");
-        client::get_application_manager_interface().request_reset_to_factory_settings_with_platform_region_authentication_securely(unk, unk_2, unk_3)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_reset_to_factory_settings_with_platform_region_authentication_securely\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 106, (unk, unk_2, unk_3) => {
+            client::get_application_manager_interface(self.kind).request_reset_to_factory_settings_with_platform_region_authentication_securely(unk, unk_2, unk_3)
+        })
     }
 
     fn calculate_user_save_data_statistics(&mut self, unk_1: u64, unk_2: u64) -> Result<(u64, u64)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> calculate_user_save_data_statistics\n");
-        client::get_application_manager_interface().calculate_user_save_data_statistics(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> calculate_user_save_data_statistics\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 200, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).calculate_user_save_data_statistics(unk_1, unk_2)
+        })
     }
 
     fn delete_user_save_data_all(&mut self, uid: Uid) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_user_save_data_all\n");
-        client::get_application_manager_interface().delete_user_save_data_all(uid)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_user_save_data_all\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 201, (uid) => {
+            client::get_application_manager_interface(self.kind).delete_user_save_data_all(uid)
+        })
     }
 
     fn delete_user_system_save_data(&mut self, uid: Uid, id: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_user_system_save_data\n");
-        client::get_application_manager_interface().delete_user_system_save_data(uid, id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_user_system_save_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 210, (uid, id) => {
+            client::get_application_manager_interface(self.kind).delete_user_system_save_data(uid, id)
+        })
     }
 
     fn delete_save_data(&mut self, space_id: SaveDataSpaceId, id: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_save_data\n");
-        client::get_application_manager_interface().delete_save_data(space_id, id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_save_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 211, (space_id, id) => {
+            client::get_application_manager_interface(self.kind).delete_save_data(space_id, id)
+        })
     }
 
     fn unregister_network_service_account(&mut self, uid: Uid) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> unregister_network_service_account\n");
-        client::get_application_manager_interface().unregister_network_service_account(uid)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> unregister_network_service_account\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 220, (uid) => {
+            client::get_application_manager_interface(self.kind).unregister_network_service_account(uid)
+        })
     }
 
     fn unregister_network_service_account_with_user_save_data_deletion(&mut self, space_id: SaveDataSpaceId, id: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> unregister_network_service_account_with_user_save_data_deletion\n");
-        client::get_application_manager_interface().unregister_network_service_account_with_user_save_data_deletion(space_id, id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> unregister_network_service_account_with_user_save_data_deletion\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 221, (space_id, id) => {
+            client::get_application_manager_interface(self.kind).unregister_network_service_account_with_user_save_data_deletion(space_id, id)
+        })
     }
 
     fn get_application_shell_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_shell_event\n");
-        client::get_application_manager_interface().get_application_shell_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_shell_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 300, () => {
+            client::get_application_manager_interface(self.kind).get_application_shell_event()
+        })
     }
 
     fn pop_application_shell_event_info(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> pop_application_shell_event_info\n");
-        client::get_application_manager_interface().pop_application_shell_event_info(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> pop_application_shell_event_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 301, (out_buf) => {
+            client::get_application_manager_interface(self.kind).pop_application_shell_event_info(out_buf)
+        })
     }
 
     fn launch_library_applet(&mut self, program_id: ProgramId) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_library_applet\n");
-        client::get_application_manager_interface().launch_library_applet(program_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_library_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 302, (program_id) => {
+            client::get_application_manager_interface(self.kind).launch_library_applet(program_id)
+        })
     }
 
     fn terminate_library_applet(&mut self, program_id: ProgramId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> terminate_library_applet\n");
-        client::get_application_manager_interface().terminate_library_applet(program_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> terminate_library_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 303, (program_id) => {
+            client::get_application_manager_interface(self.kind).terminate_library_applet(program_id)
+        })
     }
 
     fn launch_system_applet(&mut self) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_system_applet\n");
-        client::get_application_manager_interface().launch_system_applet()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_system_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 304, () => {
+            client::get_application_manager_interface(self.kind).launch_system_applet()
+        })
     }
 
     fn terminate_system_applet(&mut self, program_id: ProgramId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> terminate_system_applet\n");
-        client::get_application_manager_interface().terminate_system_applet(program_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> terminate_system_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 305, (program_id) => {
+            client::get_application_manager_interface(self.kind).terminate_system_applet(program_id)
+        })
     }
 
     fn launch_overlay_applet(&mut self) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_overlay_applet\n");
-        client::get_application_manager_interface().launch_overlay_applet()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_overlay_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 306, () => {
+            client::get_application_manager_interface(self.kind).launch_overlay_applet()
+        })
     }
 
     fn terminate_overlay_applet(&mut self, program_id: ProgramId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> terminate_overlay_applet\n");
-        client::get_application_manager_interface().terminate_overlay_applet(program_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> terminate_overlay_applet\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 307, (program_id) => {
+            client::get_application_manager_interface(self.kind).terminate_overlay_applet(program_id)
+        })
     }
 
     fn get_application_control_data(&mut self, source: ApplicationControlSource, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_control_data [source: {:?}, app_id: {:?}]\n", source, app_id);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_control_data [source: {:?}, app_id: {:?}]\n", self.caller, source, app_id);
 
-        if hb::is_extra_application(app_id) {
-            let (nacp_data, icon_data) = hb::get_extra_application_control_data(app_id)?;
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 400, (source, app_id, out_buf) => {
+            if hb::is_extra_application(app_id) {
+                let (nacp_data, icon_data) = hb::get_extra_application_control_data(app_id)?;
             
-            unsafe {
-                core::ptr::copy(nacp_data.as_ptr(), out_buf.buf as *mut u8, nacp_data.len());
-                core::ptr::copy(icon_data.as_ptr(), out_buf.buf.offset(nacp_data.len() as isize) as *mut u8, icon_data.len());
-            }
+                // The icon always follows the NACP, and neither of them is of any use truncated
+                let mut out_writer = buf::OutBufferWriter::new(out_buf);
+                out_writer.write_bytes(&nacp_data)?;
+                out_writer.write_bytes(&icon_data)?;
 
-            Ok((nacp_data.len() + icon_data.len()) as u32)
-        }
-        else {
-            client::get_application_manager_interface().get_application_control_data(source, app_id, out_buf)
-        }
+                Ok((nacp_data.len() + icon_data.len()) as u32)
+            }
+            else {
+                check_application_not_hidden(app_id)?;
+                let size = client::get_application_manager_interface(self.kind).get_application_control_data(source, app_id, out_buf.clone())?;
+                overrides::apply_control_data_overrides(app_id, out_buf, size as usize).map(|size| size as u32)
+            }
+        })
     }
 
     fn invalidate_all_application_control_cache(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> invalidate_all_application_control_cache\n");
-        client::get_application_manager_interface().invalidate_all_application_control_cache()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> invalidate_all_application_control_cache\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 401, () => {
+            // Good moment to look for new NROs too
+            hb::rescan::request_rescan();
+            order::invalidate_title_cache();
+            client::get_application_manager_interface(self.kind).invalidate_all_application_control_cache()
+        })
     }
 
     fn request_download_application_control_data(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_application_control_data\n");
-        client::get_application_manager_interface().request_download_application_control_data(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_application_control_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 402, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                // Control data is always locally available, nothing to download
+                local::make_completed_async_result(hb::check_extra_application_launch(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).request_download_application_control_data(app_id)
+            }
+        })
     }
 
     fn get_max_application_control_cache_count(&mut self) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_max_application_control_cache_count\n");
-        client::get_application_manager_interface().get_max_application_control_cache_count()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_max_application_control_cache_count\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 403, () => {
+            client::get_application_manager_interface(self.kind).get_max_application_control_cache_count()
+        })
     }
 
     fn invalidate_application_control_cache(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> invalidate_application_control_cache\n");
-        client::get_application_manager_interface().invalidate_application_control_cache(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> invalidate_application_control_cache\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 404, (app_id) => {
+            client::get_application_manager_interface(self.kind).invalidate_application_control_cache(app_id)
+        })
     }
 
     fn list_application_control_cache_entry_info(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_control_cache_entry_info\n");
-        client::get_application_manager_interface().list_application_control_cache_entry_info(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_control_cache_entry_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 405, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_control_cache_entry_info(out_buf)
+        })
     }
 
     fn get_application_control_property(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_control_property\n");
-        client::get_application_manager_interface().get_application_control_property()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_control_property\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 406, () => {
+            client::get_application_manager_interface(self.kind).get_application_control_property()
+        })
     }
 
     fn list_application_title(&mut self, app_id_buf: sf::InMapAliasBuffer, source: ApplicationControlSource, tmem_handle: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_title\n");
-        client::get_application_manager_interface().list_application_title(app_id_buf, source, tmem_handle, tmem_size)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_title\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 407, (app_id_buf, source, tmem_handle, tmem_size) => {
+            self.list_application_control_entries(false, app_id_buf, source, tmem_handle, tmem_size, core::mem::size_of::<ApplicationTitle>(), |app_id, out_entry| {
+                let title = hb::get_extra_application_title(app_id)?;
+                let title_bytes = unsafe {
+                    core::slice::from_raw_parts(&title as *const ApplicationTitle as *const u8, core::mem::size_of::<ApplicationTitle>())
+                };
+                out_entry.copy_from_slice(title_bytes);
+                Ok(())
+            })
+        })
     }
 
     fn list_application_icon(&mut self, app_id_buf: sf::InMapAliasBuffer, source: ApplicationControlSource, tmem_handle: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_icon\n");
-        client::get_application_manager_interface().list_application_icon(app_id_buf, source, tmem_handle, tmem_size)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_icon\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 408, (app_id_buf, source, tmem_handle, tmem_size) => {
+            // Icon entries are the icon size followed by the icon itself
+            self.list_application_control_entries(true, app_id_buf, source, tmem_handle, tmem_size, core::mem::size_of::<u64>() + MAX_APPLICATION_ICON_SIZE, |app_id, out_entry| {
+                let icon_data = hb::get_extra_application_icon(app_id)?;
+                let icon_size = core::cmp::min(icon_data.len(), MAX_APPLICATION_ICON_SIZE);
+                out_entry[..core::mem::size_of::<u64>()].copy_from_slice(&(icon_size as u64).to_le_bytes());
+                out_entry[core::mem::size_of::<u64>()..core::mem::size_of::<u64>() + icon_size].copy_from_slice(&icon_data[..icon_size]);
+                Ok(())
+            })
+        })
     }
 
     fn request_check_game_card_registration(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_check_game_card_registration\n");
-        client::get_application_manager_interface().request_check_game_card_registration(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_check_game_card_registration\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 502, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_check_game_card_registration(app_id)
+        })
     }
 
     fn request_game_card_registration_gold_point(&mut self, uid: Uid, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_game_card_registration_gold_point\n");
-        client::get_application_manager_interface().request_game_card_registration_gold_point(uid, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_game_card_registration_gold_point\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 503, (uid, app_id) => {
+            client::get_application_manager_interface(self.kind).request_game_card_registration_gold_point(uid, app_id)
+        })
     }
 
     fn request_register_game_card(&mut self, unk: u32, uid: Uid, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_register_game_card\n");
-        client::get_application_manager_interface().request_register_game_card(unk, uid, app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_register_game_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 504, (unk, uid, app_id) => {
+            client::get_application_manager_interface(self.kind).request_register_game_card(unk, uid, app_id)
+        })
     }
 
     fn get_game_card_mount_failure_event(&mut self) -> Result<sf::CopyHandle> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_mount_failure_event\n");
-        client::get_application_manager_interface().get_game_card_mount_failure_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_mount_failure_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 505, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_mount_failure_event()
+        })
     }
 
     fn is_game_card_inserted(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_game_card_inserted\n");
-        client::get_application_manager_interface().is_game_card_inserted()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_game_card_inserted\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 506, () => {
+            client::get_application_manager_interface(self.kind).is_game_card_inserted()
+        })
     }
 
     fn ensure_game_card_access(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> ensure_game_card_access\n");
-        client::get_application_manager_interface().ensure_game_card_access()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> ensure_game_card_access\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 507, () => {
+            client::get_application_manager_interface(self.kind).ensure_game_card_access()
+        })
     }
 
     fn get_last_game_card_mount_failure_result(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_last_game_card_mount_failure_result\n");
-        client::get_application_manager_interface().get_last_game_card_mount_failure_result()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_last_game_card_mount_failure_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 508, () => {
+            client::get_application_manager_interface(self.kind).get_last_game_card_mount_failure_result()
+        })
     }
 
     fn list_application_id_on_game_card(&mut self, out_app_id_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_id_on_game_card\n");
-        client::get_application_manager_interface().list_application_id_on_game_card(out_app_id_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_id_on_game_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 509, (out_app_id_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_id_on_game_card(out_app_id_buf)
+        })
     }
 
     fn get_game_card_platform_region(&mut self) -> Result<GameCardCompatibilityType> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_platform_region\n");
-        client::get_application_manager_interface().get_game_card_platform_region()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_platform_region\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 510, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_platform_region()
+        })
     }
 
     fn count_application_content_meta(&mut self, app_id: ApplicationId) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> count_application_content_meta\n");
-        client::get_application_manager_interface().count_application_content_meta(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> count_application_content_meta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 600, (app_id) => {
+            client::get_application_manager_interface(self.kind).count_application_content_meta(app_id)
+        })
     }
 
     fn list_application_content_meta_status(&mut self, index: u32, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_content_meta_status\n");
-        client::get_application_manager_interface().list_application_content_meta_status(index, app_id, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_content_meta_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 601, (index, app_id, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_content_meta_status(index, app_id, out_buf)
+        })
     }
 
     fn list_available_add_on_content(&mut self, unk_1: u64, unk_2: u64, out_buf: sf::OutMapAliasBuffer) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_available_add_on_content\n");
-        client::get_application_manager_interface().list_available_add_on_content(unk_1, unk_2, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_available_add_on_content\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 602, (unk_1, unk_2, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_available_add_on_content(unk_1, unk_2, out_buf)
+        })
     }
 
     fn get_owned_application_content_meta_status(&mut self, unk_1: u64, unk_2: u64) -> Result<ApplicationContentMetaStatus> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_owned_application_content_meta_status\n");
-        client::get_application_manager_interface().get_owned_application_content_meta_status(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_owned_application_content_meta_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 603, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).get_owned_application_content_meta_status(unk_1, unk_2)
+        })
     }
 
     fn register_contents_external_key(&mut self, unk_1: u64, unk_2: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> register_contents_external_key\n");
-        client::get_application_manager_interface().register_contents_external_key(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> register_contents_external_key\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 604, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).register_contents_external_key(unk_1, unk_2)
+        })
     }
 
     fn list_application_content_meta_status_with_rights_check(&mut self, index: u32, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_content_meta_status_with_rights_check\n");
-        client::get_application_manager_interface().list_application_content_meta_status_with_rights_check(index, app_id, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_content_meta_status_with_rights_check\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 605, (index, app_id, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_content_meta_status_with_rights_check(index, app_id, out_buf)
+        })
     }
 
     fn get_content_meta_storage(&mut self, unk_1: u64, unk_2: u64) -> Result<StorageId> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_content_meta_storage\n");
-        client::get_application_manager_interface().get_content_meta_storage(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_content_meta_storage\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 606, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).get_content_meta_storage(unk_1, unk_2)
+        })
     }
 
     fn list_available_add_on_content_new(&mut self, unk_1: u64, unk_2: u64, out_buf: sf::OutMapAliasBuffer) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_available_add_on_content_new\n");
-        client::get_application_manager_interface().list_available_add_on_content_new(unk_1, unk_2, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_available_add_on_content_new\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 607, (unk_1, unk_2, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_available_add_on_content_new(unk_1, unk_2, out_buf)
+        })
     }
 
     fn list_availability_assured_add_on_content(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_availability_assured_add_on_content\n");
-        client::get_application_manager_interface().list_availability_assured_add_on_content()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_availability_assured_add_on_content\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 609, () => {
+            client::get_application_manager_interface(self.kind).list_availability_assured_add_on_content()
+        })
     }
 
     fn push_download_task_list(&mut self, in_buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> push_download_task_list\n");
-        client::get_application_manager_interface().push_download_task_list(in_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> push_download_task_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 700, (in_buf) => {
+            client::get_application_manager_interface(self.kind).push_download_task_list(in_buf)
+        })
     }
 
     fn clear_task_status_list(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> clear_task_status_list\n");
-        client::get_application_manager_interface().clear_task_status_list()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> clear_task_status_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 701, () => {
+            client::get_application_manager_interface(self.kind).clear_task_status_list()
+        })
     }
 
     fn request_download_task_list(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_task_list\n");
-        client::get_application_manager_interface().request_download_task_list()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_task_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 702, () => {
+            client::get_application_manager_interface(self.kind).request_download_task_list()
+        })
     }
 
     fn request_ensure_download_task(&mut self) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_ensure_download_task\n");
-        client::get_application_manager_interface().request_ensure_download_task()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_ensure_download_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 703, () => {
+            client::get_application_manager_interface(self.kind).request_ensure_download_task()
+        })
     }
 
     fn list_download_task_status(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_download_task_status\n");
-        client::get_application_manager_interface().list_download_task_status(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_download_task_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 704, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_download_task_status(out_buf)
+        })
     }
 
     fn request_download_task_list_data(&mut self) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_task_list_data\n");
-        client::get_application_manager_interface().request_download_task_list_data()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_task_list_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 705, () => {
+            client::get_application_manager_interface(self.kind).request_download_task_list_data()
+        })
     }
 
     fn request_version_list(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_version_list\n");
-        client::get_application_manager_interface().request_version_list()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_version_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 800, () => {
+            client::get_application_manager_interface(self.kind).request_version_list()
+        })
     }
 
     fn list_version_list(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_version_list\n");
-        client::get_application_manager_interface().list_version_list(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_version_list\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 801, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_version_list(out_buf)
+        })
     }
 
     fn request_version_list_data(&mut self) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_version_list_data\n");
-        client::get_application_manager_interface().request_version_list_data()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_version_list_data\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 802, () => {
+            client::get_application_manager_interface(self.kind).request_version_list_data()
+        })
     }
 
     fn get_application_record(&mut self, app_id: ApplicationId) -> Result<ApplicationRecord> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_record\n");
-        client::get_application_manager_interface().get_application_record(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 900, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                hb::get_extra_application_record(app_id)
+            }
+            else {
+                check_application_not_hidden(app_id)?;
+                client::get_application_manager_interface(self.kind).get_application_record(app_id)
+            }
+        })
     }
 
     fn get_application_record_property(&mut self, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_record_property\n");
-        client::get_application_manager_interface().get_application_record_property(app_id, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_record_property\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 901, (app_id, out_buf) => {
+            if hb::is_extra_application(app_id) {
+                // Extras are records like any other, but with nothing to report: the layout isn't documented, so every field is left at its default (zero)
+                let mut out_writer = buf::OutBufferWriter::new(out_buf);
+                out_writer.clear();
+                Ok(())
+            }
+            else {
+                check_application_not_hidden(app_id)?;
+                client::get_application_manager_interface(self.kind).get_application_record_property(app_id, out_buf)
+            }
+        })
     }
 
     fn enable_application_auto_update(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_application_auto_update\n");
-        client::get_application_manager_interface().enable_application_auto_update(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_application_auto_update\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 902, (app_id) => {
+            client::get_application_manager_interface(self.kind).enable_application_auto_update(app_id)
+        })
     }
 
     fn disable_application_auto_update(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> disable_application_auto_update\n");
-        client::get_application_manager_interface().disable_application_auto_update(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> disable_application_auto_update\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 903, (app_id) => {
+            client::get_application_manager_interface(self.kind).disable_application_auto_update(app_id)
+        })
     }
 
     fn touch_application(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> touch_application\n");
-        client::get_application_manager_interface().touch_application(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> touch_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 904, (app_id) => {
+            client::get_application_manager_interface(self.kind).touch_application(app_id)
+        })
     }
 
     fn request_application_update(&mut self, unk_1: u64, unk_2: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_application_update\n");
-        client::get_application_manager_interface().request_application_update(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_application_update\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 905, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).request_application_update(unk_1, unk_2)
+        })
     }
 
     fn is_application_update_requested(&mut self, app_id: ApplicationId) -> Result<(bool, u32)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_application_update_requested\n");
-        client::get_application_manager_interface().is_application_update_requested(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_application_update_requested\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 906, (app_id) => {
+            client::get_application_manager_interface(self.kind).is_application_update_requested(app_id)
+        })
     }
 
     fn withdraw_application_update_request(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> withdraw_application_update_request\n");
-        client::get_application_manager_interface().withdraw_application_update_request(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> withdraw_application_update_request\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 907, (app_id) => {
+            client::get_application_manager_interface(self.kind).withdraw_application_update_request(app_id)
+        })
     }
 
     fn list_application_record_installed_content_meta(&mut self, unk_1: u64, unk_2: u64, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_record_installed_content_meta\n");
-        client::get_application_manager_interface().list_application_record_installed_content_meta(unk_1, unk_2, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_record_installed_content_meta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 908, (unk_1, unk_2, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_record_installed_content_meta(unk_1, unk_2, out_buf)
+        })
     }
 
     fn withdraw_cleanup_add_on_contents_with_no_rights_recommendation(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> withdraw_cleanup_add_on_contents_with_no_rights_recommendation\n");
-        client::get_application_manager_interface().withdraw_cleanup_add_on_contents_with_no_rights_recommendation(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> withdraw_cleanup_add_on_contents_with_no_rights_recommendation\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 909, (app_id) => {
+            client::get_application_manager_interface(self.kind).withdraw_cleanup_add_on_contents_with_no_rights_recommendation(app_id)
+        })
     }
 
     fn has_application_record(&mut self, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> has_application_record\n");
-        client::get_application_manager_interface().has_application_record(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> has_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 910, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                Ok(true)
+            }
+            else if is_hidden_application(app_id) {
+                Ok(false)
+            }
+            else {
+                client::get_application_manager_interface(self.kind).has_application_record(app_id)
+            }
+        })
     }
 
     fn set_pre_installed_application(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> set_pre_installed_application\n");
-        client::get_application_manager_interface().set_pre_installed_application()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> set_pre_installed_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 911, () => {
+            client::get_application_manager_interface(self.kind).set_pre_installed_application()
+        })
     }
 
     fn clear_pre_installed_application_flag(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> clear_pre_installed_application_flag\n");
-        client::get_application_manager_interface().clear_pre_installed_application_flag()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> clear_pre_installed_application_flag\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 912, () => {
+            client::get_application_manager_interface(self.kind).clear_pre_installed_application_flag()
+        })
     }
 
     fn list_all_application_record(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_all_application_record\n");
-        client::get_application_manager_interface().list_all_application_record()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_all_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 913, () => {
+            client::get_application_manager_interface(self.kind).list_all_application_record()
+        })
     }
 
     fn hide_application_record(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> hide_application_record\n");
-        client::get_application_manager_interface().hide_application_record()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> hide_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 914, () => {
+            client::get_application_manager_interface(self.kind).hide_application_record()
+        })
     }
 
     fn show_application_record(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> show_application_record\n");
-        client::get_application_manager_interface().show_application_record()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> show_application_record\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 915, () => {
+            client::get_application_manager_interface(self.kind).show_application_record()
+        })
     }
 
     fn is_application_auto_delete_disabled(&mut self, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_application_auto_delete_disabled\n");
-        client::get_application_manager_interface().is_application_auto_delete_disabled(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_application_auto_delete_disabled\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 916, (app_id) => {
+            client::get_application_manager_interface(self.kind).is_application_auto_delete_disabled(app_id)
+        })
     }
 
     fn request_verify_application_deprecated(&mut self, app_id: ApplicationId, tmem_handle: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_verify_application_deprecated\n");
-        client::get_application_manager_interface().request_verify_application_deprecated(app_id, tmem_handle, tmem_size)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_verify_application_deprecated\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1000, (app_id, tmem_handle, tmem_size) => {
+            if hb::is_extra_application(app_id) {
+                // There are no contents to verify, so the caller's work memory is never used
+                let _ = svc::close_handle(tmem_handle.handle);
+                local::make_completed_progress_async_result(hb::check_extra_application_launch(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).request_verify_application_deprecated(app_id, tmem_handle, tmem_size)
+            }
+        })
     }
 
     fn corrupt_application_for_debug(&mut self, unk_1: u64, unk_2: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> corrupt_application_for_debug\n");
-        client::get_application_manager_interface().corrupt_application_for_debug(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> corrupt_application_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1001, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).corrupt_application_for_debug(unk_1, unk_2)
+        })
     }
 
     fn request_verify_add_on_contents_rights(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_verify_add_on_contents_rights\n");
-        client::get_application_manager_interface().request_verify_add_on_contents_rights(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_verify_add_on_contents_rights\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1002, (app_id) => {
+            if hb::is_extra_application(app_id) {
+                local::make_completed_progress_async_result(hb::check_extra_application_launch(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).request_verify_add_on_contents_rights(app_id)
+            }
+        })
     }
 
     fn request_verify_application(&mut self, unk: u32, app_id: ApplicationId, tmem: sf::CopyHandle, tmem_size: usize) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_verify_application\n");
-        client::get_application_manager_interface().request_verify_application(unk, app_id, tmem, tmem_size)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_verify_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1003, (unk, app_id, tmem, tmem_size) => {
+            if hb::is_extra_application(app_id) {
+                // Same as above
+                let _ = svc::close_handle(tmem.handle);
+                local::make_completed_progress_async_result(hb::check_extra_application_launch(app_id))
+            }
+            else {
+                client::get_application_manager_interface(self.kind).request_verify_application(unk, app_id, tmem, tmem_size)
+            }
+        })
     }
 
     fn corrupt_content_for_debug(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> corrupt_content_for_debug\n");
-        client::get_application_manager_interface().corrupt_content_for_debug()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> corrupt_content_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1004, () => {
+            client::get_application_manager_interface(self.kind).corrupt_content_for_debug()
+        })
     }
 
     fn needs_update_vulnerability(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> needs_update_vulnerability\n");
-        client::get_application_manager_interface().needs_update_vulnerability()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> needs_update_vulnerability\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1200, () => {
+            client::get_application_manager_interface(self.kind).needs_update_vulnerability()
+        })
     }
 
     fn is_any_application_entity_installed(&mut self, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_any_application_entity_installed\n");
-        client::get_application_manager_interface().is_any_application_entity_installed(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_any_application_entity_installed\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1300, (app_id) => {
+            client::get_application_manager_interface(self.kind).is_any_application_entity_installed(app_id)
+        })
     }
 
     fn delete_application_content_entities(&mut self, unk_1: u64, unk_2: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_content_entities\n");
-        client::get_application_manager_interface().delete_application_content_entities(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_content_entities\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1301, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).delete_application_content_entities(unk_1, unk_2)
+        })
     }
 
     fn cleanup_unrecorded_application_entity(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_unrecorded_application_entity\n");
-        client::get_application_manager_interface().cleanup_unrecorded_application_entity(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_unrecorded_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1302, (app_id) => {
+            client::get_application_manager_interface(self.kind).cleanup_unrecorded_application_entity(app_id)
+        })
     }
 
     fn cleanup_add_on_contents_with_no_rights(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_add_on_contents_with_no_rights\n");
-        client::get_application_manager_interface().cleanup_add_on_contents_with_no_rights(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_add_on_contents_with_no_rights\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1303, (app_id) => {
+            client::get_application_manager_interface(self.kind).cleanup_add_on_contents_with_no_rights(app_id)
+        })
     }
 
     fn delete_application_content_entity(&mut self, unk_1: u64, unk_2: u64) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_content_entity\n");
-        client::get_application_manager_interface().delete_application_content_entity(unk_1, unk_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_content_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1304, (unk_1, unk_2) => {
+            client::get_application_manager_interface(self.kind).delete_application_content_entity(unk_1, unk_2)
+        })
     }
 
     fn delete_application_completely_for_debug(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> delete_application_completely_for_debug\n");
-        client::get_application_manager_interface().delete_application_completely_for_debug()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> delete_application_completely_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1308, () => {
+            client::get_application_manager_interface(self.kind).delete_application_completely_for_debug()
+        })
     }
 
     fn cleanup_unavailable_add_on_contents(&mut self, app_id: ApplicationId, uid: Uid) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_unavailable_add_on_contents\n");
-        client::get_application_manager_interface().cleanup_unavailable_add_on_contents(app_id, uid)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_unavailable_add_on_contents\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1309, (app_id, uid) => {
+            client::get_application_manager_interface(self.kind).cleanup_unavailable_add_on_contents(app_id, uid)
+        })
     }
 
     fn request_move_application_entity(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_move_application_entity\n");
-        client::get_application_manager_interface().request_move_application_entity()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_move_application_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1310, () => {
+            client::get_application_manager_interface(self.kind).request_move_application_entity()
+        })
     }
 
     fn estimate_size_to_move(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> estimate_size_to_move\n");
-        client::get_application_manager_interface().estimate_size_to_move()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> estimate_size_to_move\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1311, () => {
+            client::get_application_manager_interface(self.kind).estimate_size_to_move()
+        })
     }
 
     fn has_movable_entity(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> has_movable_entity\n");
-        client::get_application_manager_interface().has_movable_entity()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> has_movable_entity\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1312, () => {
+            client::get_application_manager_interface(self.kind).has_movable_entity()
+        })
     }
 
     fn cleanup_orphan_contents(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_orphan_contents\n");
-        client::get_application_manager_interface().cleanup_orphan_contents()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_orphan_contents\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1313, () => {
+            client::get_application_manager_interface(self.kind).cleanup_orphan_contents()
+        })
     }
 
     fn check_precondition_satisfied_to_move(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> check_precondition_satisfied_to_move\n");
-        client::get_application_manager_interface().check_precondition_satisfied_to_move()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> check_precondition_satisfied_to_move\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1314, () => {
+            client::get_application_manager_interface(self.kind).check_precondition_satisfied_to_move()
+        })
     }
 
     fn prepare_shutdown(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> prepare_shutdown\n");
-        client::get_application_manager_interface().prepare_shutdown()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> prepare_shutdown\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1400, () => {
+            client::get_application_manager_interface(self.kind).prepare_shutdown()
+        })
     }
 
     fn format_sd_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> format_sd_card\n");
-        client::get_application_manager_interface().format_sd_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> format_sd_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1500, () => {
+            client::get_application_manager_interface(self.kind).format_sd_card()
+        })
     }
 
     fn needs_system_update_to_format_sd_card(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> needs_system_update_to_format_sd_card\n");
-        client::get_application_manager_interface().needs_system_update_to_format_sd_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> needs_system_update_to_format_sd_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1501, () => {
+            client::get_application_manager_interface(self.kind).needs_system_update_to_format_sd_card()
+        })
     }
 
     fn get_last_sd_card_format_unexpected_result(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_last_sd_card_format_unexpected_result\n");
-        client::get_application_manager_interface().get_last_sd_card_format_unexpected_result()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_last_sd_card_format_unexpected_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1502, () => {
+            client::get_application_manager_interface(self.kind).get_last_sd_card_format_unexpected_result()
+        })
     }
 
     fn insert_sd_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> insert_sd_card\n");
-        client::get_application_manager_interface().insert_sd_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> insert_sd_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1504, () => {
+            client::get_application_manager_interface(self.kind).insert_sd_card()
+        })
     }
 
     fn remove_sd_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> remove_sd_card\n");
-        client::get_application_manager_interface().remove_sd_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> remove_sd_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1505, () => {
+            client::get_application_manager_interface(self.kind).remove_sd_card()
+        })
     }
 
     fn get_sd_card_startup_status(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_sd_card_startup_status\n");
-        client::get_application_manager_interface().get_sd_card_startup_status()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_sd_card_startup_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1506, () => {
+            client::get_application_manager_interface(self.kind).get_sd_card_startup_status()
+        })
     }
 
     fn get_system_seed_for_pseudo_device_id(&mut self) -> Result<[u8; 0x20]> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_system_seed_for_pseudo_device_id\n");
-        client::get_application_manager_interface().get_system_seed_for_pseudo_device_id()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_system_seed_for_pseudo_device_id\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1600, () => {
+            client::get_application_manager_interface(self.kind).get_system_seed_for_pseudo_device_id()
+        })
     }
 
     fn reset_system_seed_for_pseudo_device_id(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> reset_system_seed_for_pseudo_device_id\n");
-        client::get_application_manager_interface().reset_system_seed_for_pseudo_device_id()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> reset_system_seed_for_pseudo_device_id\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1601, () => {
+            client::get_application_manager_interface(self.kind).reset_system_seed_for_pseudo_device_id()
+        })
     }
 
     fn list_application_downloading_content_meta(&mut self, unk_1: u64, unk_2: u64, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_application_downloading_content_meta\n");
-        client::get_application_manager_interface().list_application_downloading_content_meta(unk_1, unk_2, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_application_downloading_content_meta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1700, (unk_1, unk_2, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_application_downloading_content_meta(unk_1, unk_2, out_buf)
+        })
     }
 
     fn get_application_view(&mut self, in_app_ids: sf::InMapAliasBuffer, out_views: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_view\n");
-
-        let app_ids = in_app_ids.get_slice::<ApplicationId>();
-        let mut real_app_ids = app_ids.to_vec();
-        real_app_ids.retain(|app_id| !hb::is_extra_application(*app_id));
-
-        let mut out_real_views: Vec<ApplicationView> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
-        client::get_application_manager_interface().get_application_view(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
-
-        let out_views_arr = out_views.get_mut_slice::<ApplicationView>();
-        let mut i: usize = 0;
-        let mut j: usize = 0;
-        for app_id in app_ids {
-            if hb::is_extra_application(*app_id) {
-                diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> Gen view of hb application {:?}\n", *app_id);
-                out_views_arr[i] = hb::gen_application_view(*app_id);
-            }
-            else {
-                diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> View of real application: {:?}\n", out_real_views[j]);
-                out_views_arr[i] = out_real_views[j];
-                j += 1;
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_view\n", self.caller);
+
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1701, (in_app_ids, out_views) => {
+            let app_ids = in_app_ids.get_slice::<ApplicationId>();
+            let extra_apps = hb::get_extra_applications();
+            let mut real_app_ids = app_ids.to_vec();
+            real_app_ids.retain(|app_id| is_real_application_in(&extra_apps, *app_id));
+
+            let mut out_real_views: Vec<ApplicationView> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
+            client::get_application_manager_interface(self.kind).get_application_view(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
+
+            let mut out_writer = buf::OutBufferWriter::new(out_views);
+            let mut j: usize = 0;
+            for app_id in app_ids {
+                if hb::is_extra_application_in(&extra_apps, *app_id) {
+                    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Gen view of hb application {:?}\n", self.caller, *app_id);
+                    out_writer.write(&hb::gen_application_view(*app_id))?;
+                }
+                else if is_hidden_application_in(&extra_apps, *app_id) {
+                    out_writer.write(&unsafe { core::mem::zeroed::<ApplicationView>() })?;
+                }
+                else {
+                    let real_view = out_real_views.get(j).ok_or(ResultCode::new(ResultDescription::BufferTooSmall.get_value()))?;
+                    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> View of real application: {:?}\n", self.caller, real_view);
+                    out_writer.write(real_view)?;
+                    j += 1;
+                }
             }
-            i += 1;
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn get_application_download_task_status(&mut self, app_id: ApplicationId) -> Result<u8> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_download_task_status\n");
-        client::get_application_manager_interface().get_application_download_task_status(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_download_task_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1702, (app_id) => {
+            client::get_application_manager_interface(self.kind).get_application_download_task_status(app_id)
+        })
     }
 
     fn get_application_view_download_error_context(&mut self, app_id: ApplicationId, out_err_ctx_buf: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_view_download_error_context\n");
-        client::get_application_manager_interface().get_application_view_download_error_context(app_id, out_err_ctx_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_view_download_error_context\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1703, (app_id, out_err_ctx_buf) => {
+            client::get_application_manager_interface(self.kind).get_application_view_download_error_context(app_id, out_err_ctx_buf)
+        })
     }
 
     fn get_application_view_with_promotion_info(&mut self, in_app_ids: sf::InMapAliasBuffer, out_data: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_view_with_promotion_info\n");
-
-        let app_ids = in_app_ids.get_slice::<ApplicationId>();
-        let mut real_app_ids = app_ids.to_vec();
-        real_app_ids.retain(|app_id| !hb::is_extra_application(*app_id));
-
-        let mut out_real_views: Vec<ApplicationViewWithPromotionInfo> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
-        client::get_application_manager_interface().get_application_view_with_promotion_info(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
-
-        let out_views_arr = out_data.get_mut_slice::<ApplicationViewWithPromotionInfo>();
-        let mut i: usize = 0;
-        let mut j: usize = 0;
-        for app_id in app_ids {
-            if hb::is_extra_application(*app_id) {
-                out_views_arr[i] = hb::gen_application_view_with_promotion_info(*app_id);
-            }
-            else {
-                out_views_arr[i] = out_real_views[j];
-                j += 1;
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_view_with_promotion_info\n", self.caller);
+
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1704, (in_app_ids, out_data) => {
+            let app_ids = in_app_ids.get_slice::<ApplicationId>();
+            let extra_apps = hb::get_extra_applications();
+            let mut real_app_ids = app_ids.to_vec();
+            real_app_ids.retain(|app_id| is_real_application_in(&extra_apps, *app_id));
+
+            let mut out_real_views: Vec<ApplicationViewWithPromotionInfo> = vec![unsafe { core::mem::zeroed() }; real_app_ids.len()];
+            client::get_application_manager_interface(self.kind).get_application_view_with_promotion_info(sf::InMapAliasBuffer::from_array(&real_app_ids), sf::OutMapAliasBuffer::from_array(&out_real_views))?;
+
+            let mut out_writer = buf::OutBufferWriter::new(out_data);
+            let mut j: usize = 0;
+            for app_id in app_ids {
+                if hb::is_extra_application_in(&extra_apps, *app_id) {
+                    out_writer.write(&hb::gen_application_view_with_promotion_info(*app_id))?;
+                }
+                else if is_hidden_application_in(&extra_apps, *app_id) {
+                    out_writer.write(&unsafe { core::mem::zeroed::<ApplicationViewWithPromotionInfo>() })?;
+                }
+                else {
+                    out_writer.write(out_real_views.get(j).ok_or(ResultCode::new(ResultDescription::BufferTooSmall.get_value()))?)?;
+                    j += 1;
+                }
             }
-            i += 1;
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn is_patch_auto_deletable_application(&mut self, app_id: ApplicationId) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_patch_auto_deletable_application\n");
-        client::get_application_manager_interface().is_patch_auto_deletable_application(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_patch_auto_deletable_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1705, (app_id) => {
+            client::get_application_manager_interface(self.kind).is_patch_auto_deletable_application(app_id)
+        })
     }
 
     fn is_notification_setup_completed(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_notification_setup_completed\n");
-        client::get_application_manager_interface().is_notification_setup_completed()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_notification_setup_completed\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1800, () => {
+            client::get_application_manager_interface(self.kind).is_notification_setup_completed()
+        })
     }
 
     fn get_last_notification_info_count(&mut self) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_last_notification_info_count\n");
-        client::get_application_manager_interface().get_last_notification_info_count()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_last_notification_info_count\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1801, () => {
+            client::get_application_manager_interface(self.kind).get_last_notification_info_count()
+        })
     }
 
     fn list_last_notification_info(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_last_notification_info\n");
-        client::get_application_manager_interface().list_last_notification_info(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_last_notification_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1802, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_last_notification_info(out_buf)
+        })
     }
 
     fn list_notification_task(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_notification_task\n");
-        client::get_application_manager_interface().list_notification_task(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_notification_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1803, (out_buf) => {
+            client::get_application_manager_interface(self.kind).list_notification_task(out_buf)
+        })
     }
 
     fn is_active_account(&mut self, unk: u32) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_active_account\n");
-        client::get_application_manager_interface().is_active_account(unk)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_active_account\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1900, (unk) => {
+            client::get_application_manager_interface(self.kind).is_active_account(unk)
+        })
     }
 
     fn request_download_application_prepurchased_rights(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_application_prepurchased_rights\n");
-        client::get_application_manager_interface().request_download_application_prepurchased_rights(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_application_prepurchased_rights\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1901, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_download_application_prepurchased_rights(app_id)
+        })
     }
 
     fn get_application_ticket_info(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_ticket_info\n");
-        client::get_application_manager_interface().get_application_ticket_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_ticket_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1902, () => {
+            client::get_application_manager_interface(self.kind).get_application_ticket_info()
+        })
     }
 
     fn request_download_application_prepurchased_rights_for_account(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_download_application_prepurchased_rights_for_account\n");
-        client::get_application_manager_interface().request_download_application_prepurchased_rights_for_account()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_download_application_prepurchased_rights_for_account\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 1903, () => {
+            client::get_application_manager_interface(self.kind).request_download_application_prepurchased_rights_for_account()
+        })
     }
 
     fn get_system_delivery_info(&mut self, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_system_delivery_info\n");
-        client::get_application_manager_interface().get_system_delivery_info(out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_system_delivery_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2000, (out_buf) => {
+            client::get_application_manager_interface(self.kind).get_system_delivery_info(out_buf)
+        })
     }
 
     fn select_latest_system_delivery_info(&mut self, system_info_buf: sf::InMapAliasBuffer, system_infos_buf: sf::InMapAliasBuffer, app_infos_buf: sf::InMapAliasBuffer) -> Result<i32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> select_latest_system_delivery_info\n");
-        client::get_application_manager_interface().select_latest_system_delivery_info(system_info_buf, system_infos_buf, app_infos_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> select_latest_system_delivery_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2001, (system_info_buf, system_infos_buf, app_infos_buf) => {
+            client::get_application_manager_interface(self.kind).select_latest_system_delivery_info(system_info_buf, system_infos_buf, app_infos_buf)
+        })
     }
 
     fn verify_delivery_protocol_version(&mut self, system_info_buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> verify_delivery_protocol_version\n");
-        client::get_application_manager_interface().verify_delivery_protocol_version(system_info_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> verify_delivery_protocol_version\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2002, (system_info_buf) => {
+            client::get_application_manager_interface(self.kind).verify_delivery_protocol_version(system_info_buf)
+        })
     }
 
     fn get_application_delivery_info(&mut self, bitmask: u32, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_delivery_info\n");
-        client::get_application_manager_interface().get_application_delivery_info(bitmask, app_id, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_delivery_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2003, (bitmask, app_id, out_buf) => {
+            client::get_application_manager_interface(self.kind).get_application_delivery_info(bitmask, app_id, out_buf)
+        })
     }
 
     fn has_all_contents_to_deliver(&mut self, array_buf: sf::InMapAliasBuffer) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> has_all_contents_to_deliver\n");
-        client::get_application_manager_interface().has_all_contents_to_deliver(array_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> has_all_contents_to_deliver\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2004, (array_buf) => {
+            client::get_application_manager_interface(self.kind).has_all_contents_to_deliver(array_buf)
+        })
     }
 
     fn compare_application_delivery_info(&mut self, buf_1: sf::InMapAliasBuffer, buf_2: sf::InMapAliasBuffer) -> Result<i32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> compare_application_delivery_info\n");
-        client::get_application_manager_interface().compare_application_delivery_info(buf_1, buf_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> compare_application_delivery_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2005, (buf_1, buf_2) => {
+            client::get_application_manager_interface(self.kind).compare_application_delivery_info(buf_1, buf_2)
+        })
     }
 
     fn can_deliver_application(&mut self, buf_1: sf::InMapAliasBuffer, buf_2: sf::InMapAliasBuffer) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> can_deliver_application\n");
-        client::get_application_manager_interface().can_deliver_application(buf_1, buf_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> can_deliver_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2006, (buf_1, buf_2) => {
+            client::get_application_manager_interface(self.kind).can_deliver_application(buf_1, buf_2)
+        })
     }
 
     fn list_content_meta_key_to_deliver_application(&mut self, unk: i32, in_buf: sf::InMapAliasBuffer, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_content_meta_key_to_deliver_application\n");
-        client::get_application_manager_interface().list_content_meta_key_to_deliver_application(unk, in_buf, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_content_meta_key_to_deliver_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2007, (unk, in_buf, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_content_meta_key_to_deliver_application(unk, in_buf, out_buf)
+        })
     }
 
     fn needs_system_update_to_deliver_application(&mut self, buf_1: sf::InMapAliasBuffer, buf_2: sf::InMapAliasBuffer) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> needs_system_update_to_deliver_application\n");
-        client::get_application_manager_interface().needs_system_update_to_deliver_application(buf_1, buf_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> needs_system_update_to_deliver_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2008, (buf_1, buf_2) => {
+            client::get_application_manager_interface(self.kind).needs_system_update_to_deliver_application(buf_1, buf_2)
+        })
     }
 
     fn estimate_required_size(&mut self, meta_key_buf: sf::InMapAliasBuffer) -> Result<usize> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> estimate_required_size\n");
-        client::get_application_manager_interface().estimate_required_size(meta_key_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> estimate_required_size\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2009, (meta_key_buf) => {
+            client::get_application_manager_interface(self.kind).estimate_required_size(meta_key_buf)
+        })
     }
 
     fn request_receive_application(&mut self, storage_id: StorageId, port: u16, ipv4_addr: u32, app_id: ApplicationId, meta_keys_buf: sf::InMapAliasBuffer) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_receive_application\n");
-        client::get_application_manager_interface().request_receive_application(storage_id, port, ipv4_addr, app_id, meta_keys_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_receive_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2010, (storage_id, port, ipv4_addr, app_id, meta_keys_buf) => {
+            client::get_application_manager_interface(self.kind).request_receive_application(storage_id, port, ipv4_addr, app_id, meta_keys_buf)
+        })
     }
 
     fn commit_receive_application(&mut self, app_id: ApplicationId) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> commit_receive_application\n");
-        client::get_application_manager_interface().commit_receive_application(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> commit_receive_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2011, (app_id) => {
+            client::get_application_manager_interface(self.kind).commit_receive_application(app_id)
+        })
     }
 
     fn get_receive_application_progress(&mut self, app_id: ApplicationId) -> Result<ReceiveApplicationProgress> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_receive_application_progress\n");
-        client::get_application_manager_interface().get_receive_application_progress(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_receive_application_progress\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2012, (app_id) => {
+            client::get_application_manager_interface(self.kind).get_receive_application_progress(app_id)
+        })
     }
 
     fn request_send_application(&mut self, port: u16, ipv4_addr: u32, app_id: ApplicationId, meta_keys_buf: sf::InMapAliasBuffer) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_send_application\n");
-        client::get_application_manager_interface().request_send_application(port, ipv4_addr, app_id, meta_keys_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_send_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2013, (port, ipv4_addr, app_id, meta_keys_buf) => {
+            client::get_application_manager_interface(self.kind).request_send_application(port, ipv4_addr, app_id, meta_keys_buf)
+        })
     }
 
     fn get_send_application_progress(&mut self, app_id: ApplicationId) -> Result<SendApplicationProgress> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_send_application_progress\n");
-        client::get_application_manager_interface().get_send_application_progress(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_send_application_progress\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2014, (app_id) => {
+            client::get_application_manager_interface(self.kind).get_send_application_progress(app_id)
+        })
     }
 
     fn compare_system_delivery_info(&mut self, buf_1: sf::InMapAliasBuffer, buf_2: sf::InMapAliasBuffer) -> Result<i32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> compare_system_delivery_info\n");
-        client::get_application_manager_interface().compare_system_delivery_info(buf_1, buf_2)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> compare_system_delivery_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2015, (buf_1, buf_2) => {
+            client::get_application_manager_interface(self.kind).compare_system_delivery_info(buf_1, buf_2)
+        })
     }
 
     fn list_not_committed_content_meta(&mut self, unk: i32, app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_not_committed_content_meta\n");
-        client::get_application_manager_interface().list_not_committed_content_meta(unk, app_id, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_not_committed_content_meta\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2016, (unk, app_id, out_buf) => {
+            client::get_application_manager_interface(self.kind).list_not_committed_content_meta(unk, app_id, out_buf)
+        })
     }
 
     fn recover_download_task(&mut self, unk: u64, array: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> recover_download_task\n");
-        client::get_application_manager_interface().recover_download_task(unk, array)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> recover_download_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2017, (unk, array) => {
+            client::get_application_manager_interface(self.kind).recover_download_task(unk, array)
+        })
     }
 
     fn get_application_delivery_info_hash(&mut self, array: sf::InMapAliasBuffer) -> Result<[u8; 0x20]> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_delivery_info_hash\n");
-        client::get_application_manager_interface().get_application_delivery_info_hash(array)
-    }
-
-    fn get_application_rights_on_client(&mut self, flags: u32, app_id: ApplicationId, uid: Uid, mut out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_rights_on_client [flags: {}, app_id: {:?}, uid: {:?}]\n", flags, app_id, uid);
-
-        if hb::is_extra_application(app_id) {
-            diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> Generating fake ApplicationRightsOnClient...\n");
-            let roc = ApplicationRightsOnClient {
-                app_id: app_id,
-                uid: uid,
-                flags_1: 1,
-                flags_2: 0,
-                unk: [0, 0, 1, 0, 0, 0]
-            };
-            out_buf.set_as(roc);
-            Ok(1)
-        }
-        else {
-            let out_buf_c = out_buf.clone();
-            diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> getting real ApplicationRightsOnClient...\n");
-            let count = client::get_application_manager_interface().get_application_rights_on_client(flags, app_id, uid, out_buf)?;
-            if count > 0 {
-                let roc_arr = out_buf_c.get_slice::<ApplicationRightsOnClient>();
-                for i in 0..count as usize {
-                    diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> got real ApplicationRightsOnClient: {:?}\n", roc_arr[i]);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_delivery_info_hash\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2018, (array) => {
+            client::get_application_manager_interface(self.kind).get_application_delivery_info_hash(array)
+        })
+    }
+
+    fn get_application_rights_on_client(&mut self, flags: u32, app_id: ApplicationId, uid: Uid, out_buf: sf::OutMapAliasBuffer) -> Result<u32> {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_rights_on_client [flags: {}, app_id: {:?}, uid: {:?}]\n", self.caller, flags, app_id, uid);
+
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2050, (flags, app_id, uid, out_buf) => {
+            if hb::is_extra_application(app_id) {
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> Generating fake ApplicationRightsOnClient...\n", self.caller);
+                hb::check_extra_application_launch(app_id)?;
+                let mut out_writer = buf::OutBufferWriter::new(out_buf);
+                out_writer.write(&hb::gen_application_rights_on_client(app_id, uid))?;
+                Ok(1)
+            }
+            else {
+                let out_buf_c = out_buf.clone();
+                diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> getting real ApplicationRightsOnClient...\n", self.caller);
+                let count = client::get_application_manager_interface(self.kind).get_application_rights_on_client(flags, app_id, uid, out_buf)?;
+                for roc in out_buf_c.get_slice::<ApplicationRightsOnClient>().iter().take(count as usize) {
+                    diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> got real ApplicationRightsOnClient: {:?}\n", self.caller, roc);
                 }
+                Ok(count)
             }
-            Ok(count)
-        }
+        })
     }
 
     fn invalidate_rights_id_cache(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> invalidate_rights_id_cache\n");
-        client::get_application_manager_interface().invalidate_rights_id_cache()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> invalidate_rights_id_cache\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2051, () => {
+            client::get_application_manager_interface(self.kind).invalidate_rights_id_cache()
+        })
     }
 
     fn get_application_terminate_result(&mut self, app_id: ApplicationId) -> Result<ResultCode> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_terminate_result\n");
-        client::get_application_manager_interface().get_application_terminate_result(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_terminate_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2100, (app_id) => {
+            client::get_application_manager_interface(self.kind).get_application_terminate_result(app_id)
+        })
     }
 
     fn get_raw_application_terminate_result(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_raw_application_terminate_result\n");
-        client::get_application_manager_interface().get_raw_application_terminate_result()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_raw_application_terminate_result\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2101, () => {
+            client::get_application_manager_interface(self.kind).get_raw_application_terminate_result()
+        })
     }
 
     fn create_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> create_rights_environment\n");
-        client::get_application_manager_interface().create_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> create_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2150, () => {
+            client::get_application_manager_interface(self.kind).create_rights_environment()
+        })
     }
 
     fn destroy_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> destroy_rights_environment\n");
-        client::get_application_manager_interface().destroy_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> destroy_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2151, () => {
+            client::get_application_manager_interface(self.kind).destroy_rights_environment()
+        })
     }
 
     fn activate_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> activate_rights_environment\n");
-        client::get_application_manager_interface().activate_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> activate_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2152, () => {
+            client::get_application_manager_interface(self.kind).activate_rights_environment()
+        })
     }
 
     fn deactivate_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> deactivate_rights_environment\n");
-        client::get_application_manager_interface().deactivate_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> deactivate_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2153, () => {
+            client::get_application_manager_interface(self.kind).deactivate_rights_environment()
+        })
     }
 
     fn force_activate_rights_context_for_exit(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> force_activate_rights_context_for_exit\n");
-        client::get_application_manager_interface().force_activate_rights_context_for_exit()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> force_activate_rights_context_for_exit\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2154, () => {
+            client::get_application_manager_interface(self.kind).force_activate_rights_context_for_exit()
+        })
     }
 
     fn update_rights_environment_status(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> update_rights_environment_status\n");
-        client::get_application_manager_interface().update_rights_environment_status()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> update_rights_environment_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2155, () => {
+            client::get_application_manager_interface(self.kind).update_rights_environment_status()
+        })
     }
 
     fn create_rights_environment_for_micro_application_preomia(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> create_rights_environment_for_micro_application_preomia\n");
-        client::get_application_manager_interface().create_rights_environment_for_micro_application_preomia()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> create_rights_environment_for_micro_application_preomia\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2156, () => {
+            client::get_application_manager_interface(self.kind).create_rights_environment_for_micro_application_preomia()
+        })
     }
 
     fn add_target_application_to_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> add_target_application_to_rights_environment\n");
-        client::get_application_manager_interface().add_target_application_to_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> add_target_application_to_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2160, () => {
+            client::get_application_manager_interface(self.kind).add_target_application_to_rights_environment()
+        })
     }
 
     fn set_users_to_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> set_users_to_rights_environment\n");
-        client::get_application_manager_interface().set_users_to_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> set_users_to_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2161, () => {
+            client::get_application_manager_interface(self.kind).set_users_to_rights_environment()
+        })
     }
 
     fn get_rights_environment_status(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_rights_environment_status\n");
-        client::get_application_manager_interface().get_rights_environment_status()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_rights_environment_status\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2170, () => {
+            client::get_application_manager_interface(self.kind).get_rights_environment_status()
+        })
     }
 
     fn get_rights_environment_status_changed_event(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_rights_environment_status_changed_event\n");
-        client::get_application_manager_interface().get_rights_environment_status_changed_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_rights_environment_status_changed_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2171, () => {
+            client::get_application_manager_interface(self.kind).get_rights_environment_status_changed_event()
+        })
     }
 
     fn request_extend_expiration_in_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_extend_expiration_in_rights_environment\n");
-        client::get_application_manager_interface().request_extend_expiration_in_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_extend_expiration_in_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2180, () => {
+            client::get_application_manager_interface(self.kind).request_extend_expiration_in_rights_environment()
+        })
     }
 
     fn get_result_of_extend_expiration_in_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_result_of_extend_expiration_in_rights_environment\n");
-        client::get_application_manager_interface().get_result_of_extend_expiration_in_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_result_of_extend_expiration_in_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2181, () => {
+            client::get_application_manager_interface(self.kind).get_result_of_extend_expiration_in_rights_environment()
+        })
     }
 
     fn set_active_rights_context_using_state_to_rights_environment(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> set_active_rights_context_using_state_to_rights_environment\n");
-        client::get_application_manager_interface().set_active_rights_context_using_state_to_rights_environment()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> set_active_rights_context_using_state_to_rights_environment\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2182, () => {
+            client::get_application_manager_interface(self.kind).set_active_rights_context_using_state_to_rights_environment()
+        })
     }
 
     fn get_rights_environment_handle_for_application(&mut self, unk: u64) -> Result<u64> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_rights_environment_handle_for_application\n");
-        client::get_application_manager_interface().get_rights_environment_handle_for_application(unk)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_rights_environment_handle_for_application\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2190, (unk) => {
+            client::get_application_manager_interface(self.kind).get_rights_environment_handle_for_application(unk)
+        })
     }
 
     fn get_rights_environment_count_for_debug(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_rights_environment_count_for_debug\n");
-        client::get_application_manager_interface().get_rights_environment_count_for_debug()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_rights_environment_count_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2199, () => {
+            client::get_application_manager_interface(self.kind).get_rights_environment_count_for_debug()
+        })
     }
 
     fn get_game_card_application_copy_identifier(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_game_card_application_copy_identifier\n");
-        client::get_application_manager_interface().get_game_card_application_copy_identifier()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_game_card_application_copy_identifier\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2200, () => {
+            client::get_application_manager_interface(self.kind).get_game_card_application_copy_identifier()
+        })
     }
 
     fn get_installed_application_copy_identifier(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_installed_application_copy_identifier\n");
-        client::get_application_manager_interface().get_installed_application_copy_identifier()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_installed_application_copy_identifier\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2201, () => {
+            client::get_application_manager_interface(self.kind).get_installed_application_copy_identifier()
+        })
     }
 
     fn request_report_active_elicence(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_report_active_elicence\n");
-        client::get_application_manager_interface().request_report_active_elicence()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_report_active_elicence\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2250, () => {
+            client::get_application_manager_interface(self.kind).request_report_active_elicence()
+        })
     }
 
     fn list_event_log(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_event_log\n");
-        client::get_application_manager_interface().list_event_log()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_event_log\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2300, () => {
+            client::get_application_manager_interface(self.kind).list_event_log()
+        })
     }
 
     fn perform_auto_update_by_application_id(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> perform_auto_update_by_application_id\n");
-        client::get_application_manager_interface().perform_auto_update_by_application_id()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> perform_auto_update_by_application_id\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2350, () => {
+            client::get_application_manager_interface(self.kind).perform_auto_update_by_application_id()
+        })
     }
 
     fn request_no_download_rights_error_resolution(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_no_download_rights_error_resolution\n");
-        client::get_application_manager_interface().request_no_download_rights_error_resolution(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_no_download_rights_error_resolution\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2351, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_no_download_rights_error_resolution(app_id)
+        })
     }
 
     fn request_resolve_no_download_rights_error(&mut self, app_id: ApplicationId) -> Result<(sf::CopyHandle, Shared<dyn sf::IObject>)> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_resolve_no_download_rights_error\n");
-        client::get_application_manager_interface().request_resolve_no_download_rights_error(app_id)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_resolve_no_download_rights_error\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2352, (app_id) => {
+            client::get_application_manager_interface(self.kind).request_resolve_no_download_rights_error(app_id)
+        })
     }
 
     fn get_application_download_task_info(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_download_task_info\n");
-        client::get_application_manager_interface().get_application_download_task_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_download_task_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2353, () => {
+            client::get_application_manager_interface(self.kind).get_application_download_task_info()
+        })
     }
 
     fn prioritize_application_background_task(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> prioritize_application_background_task\n");
-        client::get_application_manager_interface().prioritize_application_background_task()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> prioritize_application_background_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2354, () => {
+            client::get_application_manager_interface(self.kind).prioritize_application_background_task()
+        })
     }
 
     fn prefer_storage_efficient_update(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> prefer_storage_efficient_update\n");
-        client::get_application_manager_interface().prefer_storage_efficient_update()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> prefer_storage_efficient_update\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2355, () => {
+            client::get_application_manager_interface(self.kind).prefer_storage_efficient_update()
+        })
     }
 
     fn request_storage_efficient_update_preferible(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> request_storage_efficient_update_preferible\n");
-        client::get_application_manager_interface().request_storage_efficient_update_preferible()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> request_storage_efficient_update_preferible\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2356, () => {
+            client::get_application_manager_interface(self.kind).request_storage_efficient_update_preferible()
+        })
     }
 
     fn get_promotion_info(&mut self, app_id_buf: sf::InMapAliasBuffer, uid_buf: sf::InMapAliasBuffer, out_buf: sf::OutMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_promotion_info\n");
-        client::get_application_manager_interface().get_promotion_info(app_id_buf, uid_buf, out_buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_promotion_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2400, (app_id_buf, uid_buf, out_buf) => {
+            client::get_application_manager_interface(self.kind).get_promotion_info(app_id_buf, uid_buf, out_buf)
+        })
     }
 
     fn count_promotion_info(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> count_promotion_info\n");
-        client::get_application_manager_interface().count_promotion_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> count_promotion_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2401, () => {
+            client::get_application_manager_interface(self.kind).count_promotion_info()
+        })
     }
 
     fn list_promotion_info(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> list_promotion_info\n");
-        client::get_application_manager_interface().list_promotion_info()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> list_promotion_info\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2402, () => {
+            client::get_application_manager_interface(self.kind).list_promotion_info()
+        })
     }
 
     fn import_promotion_json_for_debug(&mut self, buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> import_promotion_json_for_debug\n");
-        client::get_application_manager_interface().import_promotion_json_for_debug(buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> import_promotion_json_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2403, (buf) => {
+            client::get_application_manager_interface(self.kind).import_promotion_json_for_debug(buf)
+        })
     }
 
     fn clear_promotion_info_for_debug(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> clear_promotion_info_for_debug\n");
-        client::get_application_manager_interface().clear_promotion_info_for_debug()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> clear_promotion_info_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2404, () => {
+            client::get_application_manager_interface(self.kind).clear_promotion_info_for_debug()
+        })
     }
 
     fn confirm_available_time(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> confirm_available_time\n");
-        client::get_application_manager_interface().confirm_available_time()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> confirm_available_time\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2500, () => {
+            client::get_application_manager_interface(self.kind).confirm_available_time()
+        })
     }
 
     fn create_application_resource(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> create_application_resource\n");
-        client::get_application_manager_interface().create_application_resource()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> create_application_resource\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2510, () => {
+            client::get_application_manager_interface(self.kind).create_application_resource()
+        })
     }
 
     fn get_application_resource(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_resource\n");
-        client::get_application_manager_interface().get_application_resource()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_resource\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2511, () => {
+            client::get_application_manager_interface(self.kind).get_application_resource()
+        })
     }
 
     fn launch_micro_application_preomia(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> launch_micro_application_preomia\n");
-        client::get_application_manager_interface().launch_micro_application_preomia()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> launch_micro_application_preomia\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2513, () => {
+            client::get_application_manager_interface(self.kind).launch_micro_application_preomia()
+        })
     }
 
     fn clear_task_of_async_task_manager(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> clear_task_of_async_task_manager\n");
-        client::get_application_manager_interface().clear_task_of_async_task_manager()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> clear_task_of_async_task_manager\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2514, () => {
+            client::get_application_manager_interface(self.kind).clear_task_of_async_task_manager()
+        })
     }
 
     fn cleanup_all_placeholder_and_fragments_if_no_task(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> cleanup_all_placeholder_and_fragments_if_no_task\n");
-        client::get_application_manager_interface().cleanup_all_placeholder_and_fragments_if_no_task()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> cleanup_all_placeholder_and_fragments_if_no_task\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2515, () => {
+            client::get_application_manager_interface(self.kind).cleanup_all_placeholder_and_fragments_if_no_task()
+        })
     }
 
     fn ensure_application_certificate(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> ensure_application_certificate\n");
-        client::get_application_manager_interface().ensure_application_certificate()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> ensure_application_certificate\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2516, () => {
+            client::get_application_manager_interface(self.kind).ensure_application_certificate()
+        })
     }
 
     fn create_application_instance(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> create_application_instance\n");
-        client::get_application_manager_interface().create_application_instance()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> create_application_instance\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2517, () => {
+            client::get_application_manager_interface(self.kind).create_application_instance()
+        })
     }
 
     fn update_qualification_for_debug(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> update_qualification_for_debug\n");
-        client::get_application_manager_interface().update_qualification_for_debug()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> update_qualification_for_debug\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2518, () => {
+            client::get_application_manager_interface(self.kind).update_qualification_for_debug()
+        })
     }
 
     fn is_qualification_transition_supported(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_qualification_transition_supported\n");
-        client::get_application_manager_interface().is_qualification_transition_supported()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_qualification_transition_supported\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2519, () => {
+            client::get_application_manager_interface(self.kind).is_qualification_transition_supported()
+        })
     }
 
     fn is_qualification_transition_supported_by_process_id(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_qualification_transition_supported_by_process_id\n");
-        client::get_application_manager_interface().is_qualification_transition_supported_by_process_id()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_qualification_transition_supported_by_process_id\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2520, () => {
+            client::get_application_manager_interface(self.kind).is_qualification_transition_supported_by_process_id()
+        })
     }
 
     fn get_rights_user_changed_event(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_rights_user_changed_event\n");
-        client::get_application_manager_interface().get_rights_user_changed_event()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_rights_user_changed_event\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2521, () => {
+            client::get_application_manager_interface(self.kind).get_rights_user_changed_event()
+        })
     }
 
     fn get_application_id_of_preomia(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_id_of_preomia\n");
-        client::get_application_manager_interface().get_application_id_of_preomia()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_id_of_preomia\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 2800, () => {
+            client::get_application_manager_interface(self.kind).get_application_id_of_preomia()
+        })
     }
 
     fn register_device_lock_key(&mut self, buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> register_device_lock_key\n");
-        client::get_application_manager_interface().register_device_lock_key(buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> register_device_lock_key\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3000, (buf) => {
+            client::get_application_manager_interface(self.kind).register_device_lock_key(buf)
+        })
     }
 
     fn unregister_device_lock_key(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> unregister_device_lock_key\n");
-        client::get_application_manager_interface().unregister_device_lock_key()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> unregister_device_lock_key\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3001, () => {
+            client::get_application_manager_interface(self.kind).unregister_device_lock_key()
+        })
     }
 
     fn verify_device_lock_key(&mut self, buf: sf::InMapAliasBuffer) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> verify_device_lock_key\n");
-        client::get_application_manager_interface().verify_device_lock_key(buf)
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> verify_device_lock_key\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3002, (buf) => {
+            client::get_application_manager_interface(self.kind).verify_device_lock_key(buf)
+        })
     }
 
     fn hide_application_icon(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> hide_application_icon\n");
-        client::get_application_manager_interface().hide_application_icon()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> hide_application_icon\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3003, () => {
+            client::get_application_manager_interface(self.kind).hide_application_icon()
+        })
     }
 
     fn show_application_icon(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> show_application_icon\n");
-        client::get_application_manager_interface().show_application_icon()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> show_application_icon\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3004, () => {
+            client::get_application_manager_interface(self.kind).show_application_icon()
+        })
     }
 
     fn hide_application_title(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> hide_application_title\n");
-        client::get_application_manager_interface().hide_application_title()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> hide_application_title\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3005, () => {
+            client::get_application_manager_interface(self.kind).hide_application_title()
+        })
     }
 
     fn show_application_title(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> show_application_title\n");
-        client::get_application_manager_interface().show_application_title()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> show_application_title\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3006, () => {
+            client::get_application_manager_interface(self.kind).show_application_title()
+        })
     }
 
     fn enable_game_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_game_card\n");
-        client::get_application_manager_interface().enable_game_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_game_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3007, () => {
+            client::get_application_manager_interface(self.kind).enable_game_card()
+        })
     }
 
     fn disable_game_card(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> disable_game_card\n");
-        client::get_application_manager_interface().disable_game_card()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> disable_game_card\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3008, () => {
+            client::get_application_manager_interface(self.kind).disable_game_card()
+        })
     }
 
     fn enable_local_content_share(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> enable_local_content_share\n");
-        client::get_application_manager_interface().enable_local_content_share()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> enable_local_content_share\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3009, () => {
+            client::get_application_manager_interface(self.kind).enable_local_content_share()
+        })
     }
 
     fn disable_local_content_share(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> disable_local_content_share\n");
-        client::get_application_manager_interface().disable_local_content_share()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> disable_local_content_share\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3010, () => {
+            client::get_application_manager_interface(self.kind).disable_local_content_share()
+        })
     }
 
     fn is_application_icon_hidden(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_application_icon_hidden\n");
-        client::get_application_manager_interface().is_application_icon_hidden()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_application_icon_hidden\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3011, () => {
+            client::get_application_manager_interface(self.kind).is_application_icon_hidden()
+        })
     }
 
     fn is_application_title_hidden(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_application_title_hidden\n");
-        client::get_application_manager_interface().is_application_title_hidden()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_application_title_hidden\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3012, () => {
+            client::get_application_manager_interface(self.kind).is_application_title_hidden()
+        })
     }
 
     fn is_game_card_enabled(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_game_card_enabled\n");
-        client::get_application_manager_interface().is_game_card_enabled()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_game_card_enabled\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3013, () => {
+            client::get_application_manager_interface(self.kind).is_game_card_enabled()
+        })
     }
 
     fn is_local_content_share_enabled(&mut self) -> Result<bool> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> is_local_content_share_enabled\n");
-        client::get_application_manager_interface().is_local_content_share_enabled()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> is_local_content_share_enabled\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 3014, () => {
+            client::get_application_manager_interface(self.kind).is_local_content_share_enabled()
+        })
     }
 
     fn get_application_certificate(&mut self) -> Result<()> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface -> get_application_certificate\n");
-        client::get_application_manager_interface().get_application_certificate()
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "mitm:IApplicationManagerInterface [{}] -> get_application_certificate\n", self.caller);
+        trace_command!(self.kind, self.caller, TraceInterface::ApplicationManager, 9999, () => {
+            client::get_application_manager_interface(self.kind).get_application_certificate()
+        })
     }
 }
 
 pub struct ServiceGetterInterface<const K: GetterServiceKind> {
-    session: sf::Session
+    session: sf::Session,
+    caller: CallerInfo
 }
 
 impl<const K: GetterServiceKind> sf::IObject for ServiceGetterInterface<K> {
@@ -1900,22 +2738,31 @@ impl<const K: GetterServiceKind> sf::IObject for ServiceGetterInterface<K> {
 
 impl<const K: GetterServiceKind> server::IMitmServerObject for ServiceGetterInterface<K> {
     fn new(info: sm::MitmProcessInfo) -> Self {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "Opening NS ({:?}) mitm from process {:?}\n", K, ProgramId(info.program_id));
-        Self { session: sf::Session::new() }
+        let caller = CallerInfo::from_process_info(info);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "Opening NS ({:?}) mitm from process {}\n", K, caller);
+        Self { session: sf::Session::new(), caller }
     }
 }
 
 impl<const K: GetterServiceKind> IServiceGetterInterface for ServiceGetterInterface<K> {
     fn get_read_only_application_control_data_interface(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "Opening RO control data intf!\n");
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "Opening RO control data intf ({:?}) for {}!\n", K, self.caller);
+        trace_command!(K, self.caller, TraceInterface::ServiceGetter, 7989, () => {
+            client::check_read_only_application_control_data_interface(K)?;
 
-        Ok(Shared::new(ReadOnlyApplicationControlDataInterface::new()))
+            let intf: Shared<dyn sf::IObject> = Shared::new(ReadOnlyApplicationControlDataInterface::new(K, self.caller));
+            Ok(intf)
+        })
     }
 
     fn get_application_manager_interface(&mut self) -> Result<Shared<dyn sf::IObject>> {
-        diag_log!(log::LmLogger { log::LogSeverity::Info, true } => "Opening appman intf!\n");
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "Opening appman intf ({:?}) for {}!\n", K, self.caller);
+        trace_command!(K, self.caller, TraceInterface::ServiceGetter, 7996, () => {
+            client::check_application_manager_interface(K)?;
 
-        Ok(Shared::new(ApplicationManagerInterface::new()))
+            let intf: Shared<dyn sf::IObject> = Shared::new(ApplicationManagerInterface::new(K, self.caller));
+            Ok(intf)
+        })
     }
 }
 
@@ -1924,7 +2771,10 @@ impl<const K: GetterServiceKind> server::IMitmService for ServiceGetterInterface
         get_getter_service_name::<K>()
     }
 
-    fn should_mitm(_info: sm::MitmProcessInfo) -> bool {
-        true
+    fn should_mitm(info: sm::MitmProcessInfo) -> bool {
+        let program_id = ProgramId(info.program_id);
+        let should_mitm = config::get().mitm_filter.matches(program_id);
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Info, true } => "NS ({:?}) mitm for process {} -> {}\n", K, program_id, should_mitm);
+        should_mitm
     }
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+use alloc::alloc::{alloc_zeroed, dealloc, Layout};
+use nx::result::*;
+use nashe::results::ResultDescription;
+use nx::ipc::sf;
+use nx::svc;
+use nx::vmem;
+
+pub const PAGE_SIZE: usize = 0x1000;
+
+// Transfer memory sent by a caller, mapped into our address space while this lives
+
+pub struct MappedTransferMemory {
+    handle: svc::Handle,
+    address: *mut u8,
+    size: usize
+}
+
+impl MappedTransferMemory {
+    pub fn map(handle: sf::CopyHandle, size: usize) -> Result<Self> {
+        let address = vmem::allocate(size)? as *mut u8;
+        // Callers create it without any permissions, so that they don't touch it meanwhile
+        svc::map_transfer_memory(handle.handle, address, size, svc::MemoryPermission::None())?;
+        Ok(Self { handle: handle.handle, address, size })
+    }
+
+    pub fn get_mut_slice(&mut self) -> &mut [u8] {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.address, self.size)
+        }
+    }
+}
+
+impl Drop for MappedTransferMemory {
+    fn drop(&mut self) {
+        let _ = svc::unmap_transfer_memory(self.handle, self.address, self.size);
+        let _ = svc::close_handle(self.handle);
+    }
+}
+
+// Transfer memory backed by our own pages, to hand to the real service
+
+pub struct OwnedTransferMemory {
+    handle: svc::Handle,
+    address: *mut u8,
+    layout: Layout
+}
+
+impl OwnedTransferMemory {
+    pub fn new(size: usize) -> Result<Self> {
+        let size = (size + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+        let layout = Layout::from_size_align(size, PAGE_SIZE).map_err(|_| ResultCode::new(ResultDescription::OutOfMemory.get_value()))?;
+        let address = unsafe { alloc_zeroed(layout) };
+        if address.is_null() {
+            return Err(ResultCode::new(ResultDescription::OutOfMemory.get_value()));
+        }
+
+        match svc::create_transfer_memory(address, size, svc::MemoryPermission::None()) {
+            Ok(handle) => Ok(Self { handle, address, layout }),
+            Err(rc) => {
+                unsafe {
+                    dealloc(address, layout);
+                }
+                Err(rc)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn get_handle(&self) -> sf::CopyHandle {
+        sf::CopyHandle::from(self.handle)
+    }
+
+    #[inline]
+    pub fn get_size(&self) -> usize {
+        self.layout.size()
+    }
+
+    // Our pages are only accessible again once the transfer memory is gone, so this closes it first
+    // Only meant to be called once the service it was handed to is done with it (and released it)
+
+    pub fn close_and_get_slice(&mut self) -> &[u8] {
+        if self.handle != svc::INVALID_HANDLE {
+            let _ = svc::close_handle(self.handle);
+            self.handle = svc::INVALID_HANDLE;
+        }
+
+        unsafe {
+            core::slice::from_raw_parts(self.address, self.layout.size())
+        }
+    }
+}
+
+impl Drop for OwnedTransferMemory {
+    fn drop(&mut self) {
+        if self.handle != svc::INVALID_HANDLE {
+            let _ = svc::close_handle(self.handle);
+        }
+        unsafe {
+            dealloc(self.address, self.layout);
+        }
+    }
+}
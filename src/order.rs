@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use alloc::collections::BTreeMap;
+use nx::result::*;
+use nashe::results::{ResultDescription, ResultDisplay};
+use nashe::parse::parse_id;
+use nx::ipc::sf;
+use nx::fs;
+use nx::sync;
+use nx::diag::log;
+use crate::logger;
+use crate::config;
+use crate::hb;
+use crate::overrides;
+use crate::ns::{self, client, ApplicationControlProperty, ApplicationControlSource, ApplicationId, ApplicationRecord, ApplicationTitle, GetterServiceKind, IApplicationManagerInterface};
+
+// How the merged (real + extra) record list is ordered before handing it to callers
+// Pinned titles always come first (in their configured order), the policy decides the rest
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum OrderPolicy {
+    // Real records in ns order, then extra ones
+    #[default]
+    Default,
+    // Alphabetically, by NACP title
+    Title,
+    // Extra applications launched this boot count as the most recently played ones
+    LastPlayed,
+    // Titles listed in the order file go first, in that order
+    File
+}
+
+impl OrderPolicy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "default" => Some(OrderPolicy::Default),
+            "title" => Some(OrderPolicy::Title),
+            "last_played" => Some(OrderPolicy::LastPlayed),
+            "file" => Some(OrderPolicy::File),
+            _ => None
+        }
+    }
+}
+
+// One application ID per line, empty lines and ";" comments are skipped
+
+pub const ORDER_FILE_PATH: &str = "sdmc:/nashe/order.txt";
+
+fn read_order_file() -> Result<Vec<ApplicationId>> {
+    let mut file = fs::open_file(String::from(ORDER_FILE_PATH), fs::FileOpenOption::Read())?;
+    let mut data: Vec<u8> = vec![0; file.get_size()?];
+    file.read_array(&mut data)?;
+
+    let data_str = String::from_utf8_lossy(&data);
+    Ok(data_str.lines().map(|line| line.trim()).filter(|line| !line.is_empty() && !line.starts_with(';')).filter_map(parse_id).map(ApplicationId).collect())
+}
+
+// The order file is only re-read on rescans (and the title cache cleared), both from the rescan thread, hence the locks
+
+static mut G_ORDER_FILE_APP_IDS: Option<Vec<ApplicationId>> = None;
+static mut G_ORDER_FILE_READ: bool = false;
+static mut G_ORDER_FILE_LOCK: sync::Mutex = sync::Mutex::new(false);
+
+// Returns whether the order file changed since it was last read (only warning about a missing/broken one then, not on every rescan)
+
+pub fn reload_order_file() -> bool {
+    if config::get().order_policy != OrderPolicy::File {
+        return false;
+    }
+
+    let app_ids_rc = read_order_file();
+    let app_ids = app_ids_rc.as_ref().ok().cloned();
+    let changed = unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_ORDER_FILE_LOCK);
+        let changed = !G_ORDER_FILE_READ || (G_ORDER_FILE_APP_IDS != app_ids);
+        G_ORDER_FILE_APP_IDS = app_ids;
+        G_ORDER_FILE_READ = true;
+        changed
+    };
+
+    if changed {
+        if let Err(rc) = app_ids_rc {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to read order file, using default order: {}\n", ResultDisplay(rc.get_value()));
+        }
+    }
+    changed
+}
+
+fn get_order_file_app_ids() -> Option<Vec<ApplicationId>> {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_ORDER_FILE_LOCK);
+        G_ORDER_FILE_APP_IDS.clone()
+    }
+}
+
+// Titles are cached, since listing records happens way more often than titles change
+// Titles which couldn't be read are cached as empty keys, which sort last
+
+static mut G_TITLE_KEYS: BTreeMap<ApplicationId, String> = BTreeMap::new();
+static mut G_TITLE_KEYS_LOCK: sync::Mutex = sync::Mutex::new(false);
+
+fn make_title_key(title: &ApplicationTitle) -> String {
+    let name_len = title.name.iter().position(|ch| *ch == 0).unwrap_or(title.name.len());
+    String::from_utf8_lossy(&title.name[..name_len]).to_lowercase()
+}
+
+fn read_real_application_title(app_id: ApplicationId) -> Result<ApplicationTitle> {
+    // Only the NACP is needed, not the icon after it
+    let nacp_buf: [ApplicationControlProperty; 1] = [unsafe { core::mem::zeroed() }];
+    let size = client::get_application_manager_interface(GetterServiceKind::AM2).get_application_control_data(ApplicationControlSource::Storage, app_id, sf::OutMapAliasBuffer::from_array(&nacp_buf))?;
+    if (size as usize) < core::mem::size_of::<ApplicationControlProperty>() {
+        return Err(ResultCode::new(ResultDescription::InvalidNacpSize.get_value()));
+    }
+
+    // Relabeled titles are sorted by their new name
+    let mut nacp = nacp_buf[0];
+    overrides::apply_nacp_override(app_id, &mut nacp);
+    Ok(nacp.titles.iter().find(|title| title.name[0] != 0).copied().unwrap_or(nacp.titles[0]))
+}
+
+fn get_title_key(app_id: ApplicationId) -> String {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_TITLE_KEYS_LOCK);
+        if let Some(title_key) = G_TITLE_KEYS.get(&app_id) {
+            return title_key.clone();
+        }
+    }
+
+    let title_rc = if hb::is_extra_application(app_id) {
+        hb::get_extra_application_title(app_id)
+    }
+    else {
+        read_real_application_title(app_id)
+    };
+    let title_key = match title_rc {
+        Ok(title) => make_title_key(&title),
+        Err(rc) => {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to get the title of {} for sorting: {}\n", app_id, ResultDisplay(rc.get_value()));
+            String::new()
+        }
+    };
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_TITLE_KEYS_LOCK);
+        G_TITLE_KEYS.insert(app_id, title_key.clone());
+    }
+    title_key
+}
+
+pub fn invalidate_title_cache() {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_TITLE_KEYS_LOCK);
+        G_TITLE_KEYS.clear();
+    }
+}
+
+pub fn invalidate_title_key(app_id: ApplicationId) {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_TITLE_KEYS_LOCK);
+        G_TITLE_KEYS.remove(&app_id);
+    }
+}
+
+// Extra application launches we've seen, as (app_id, launch counter) pairs
+
+static mut G_LAUNCHES: Vec<(ApplicationId, u64)> = Vec::new();
+static mut G_LAUNCH_COUNTER: u64 = 0;
+
+pub fn notify_extra_application_launch(app_id: ApplicationId) {
+    unsafe {
+        G_LAUNCH_COUNTER += 1;
+        G_LAUNCHES.retain(|(launched_app_id, _)| *launched_app_id != app_id);
+        G_LAUNCHES.push((app_id, G_LAUNCH_COUNTER));
+    }
+
+    if config::get().order_policy == OrderPolicy::LastPlayed {
+        ns::event::signal_record_update();
+    }
+}
+
+fn get_last_launch(app_id: ApplicationId) -> Option<u64> {
+    unsafe {
+        G_LAUNCHES.iter().find(|(launched_app_id, _)| *launched_app_id == app_id).map(|(_, counter)| *counter)
+    }
+}
+
+fn find_position(app_ids: &[ApplicationId], app_id: ApplicationId) -> usize {
+    app_ids.iter().position(|other_app_id| *other_app_id == app_id).unwrap_or(usize::MAX)
+}
+
+// All sorts are stable, so anything the policy considers equal keeps its merged order
+
+pub fn sort_records(records: &mut [ApplicationRecord]) {
+    let config = config::get();
+    match config.order_policy {
+        OrderPolicy::Default => {},
+        OrderPolicy::Title => records.sort_by_cached_key(|record| {
+            let title_key = get_title_key(record.app_id);
+            (title_key.is_empty(), title_key)
+        }),
+        OrderPolicy::LastPlayed => {
+            // ns already keeps real records sorted this way, launched extras go before them and the rest after them
+            records.sort_by_key(|record| match (hb::is_extra_application(record.app_id), get_last_launch(record.app_id)) {
+                (true, Some(counter)) => (0, u64::MAX - counter),
+                (false, _) => (1, 0),
+                (true, None) => (2, 0)
+            });
+        },
+        OrderPolicy::File => {
+            // Read at boot and on every rescan, nothing to sort by if it's missing or broken
+            if let Some(app_ids) = get_order_file_app_ids() {
+                records.sort_by_key(|record| find_position(&app_ids, record.app_id));
+            }
+        }
+    }
+
+    if !config.pinned_apps.is_empty() {
+        records.sort_by_key(|record| find_position(&config.pinned_apps, record.app_id));
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::vec::Vec;
+use alloc::collections::BTreeMap;
+use nx::result::*;
+use nx::ipc::sf;
+use nx::fs;
+use nx::sync;
+use nx::diag::log;
+use crate::logger;
+use crate::order;
+use crate::ns::{buf, ApplicationControlProperty, ApplicationId, ApplicationTitle, MAX_APPLICATION_ICON_SIZE};
+
+// Per-title control data overrides for real applications, laid out as:
+// - "sdmc:/nashe/overrides/<app_id>/control.nacp": non-empty title names/authors replace the real ones
+// - "sdmc:/nashe/overrides/<app_id>/icon.jpg": replaces the real icon
+
+pub const OVERRIDES_DIRECTORY: &str = "sdmc:/nashe/overrides";
+
+fn read_override_file(app_id: ApplicationId, name: &str) -> Option<Vec<u8>> {
+    let mut file = fs::open_file(format!("{}/{:016X}/{}", OVERRIDES_DIRECTORY, app_id.0, name), fs::FileOpenOption::Read()).ok()?;
+    let mut data: Vec<u8> = vec![0; file.get_size().ok()?];
+    file.read_array(&mut data).ok()?;
+    Some(data)
+}
+
+fn read_override_nacp(app_id: ApplicationId) -> Option<ApplicationControlProperty> {
+    let nacp_data = read_override_file(app_id, "control.nacp")?;
+    if nacp_data.len() != core::mem::size_of::<ApplicationControlProperty>() {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Ignoring NACP override of {} with invalid size {:#X}\n", app_id, nacp_data.len());
+        return None;
+    }
+
+    Some(unsafe {
+        core::ptr::read_unaligned(nacp_data.as_ptr() as *const ApplicationControlProperty)
+    })
+}
+
+fn read_override_icon(app_id: ApplicationId) -> Option<Vec<u8>> {
+    let icon_data = read_override_file(app_id, "icon.jpg")?;
+    if icon_data.is_empty() || (icon_data.len() > MAX_APPLICATION_ICON_SIZE) {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Ignoring icon override of {} with invalid size {:#X}\n", app_id, icon_data.len());
+        return None;
+    }
+
+    Some(icon_data)
+}
+
+// Parsed overrides are cached per title (titles without any included), control data is fetched way too often to hit the SD card every time
+// Cached titles are re-read on rescans, which is when changed override files get picked up
+
+#[derive(Clone, PartialEq, Eq)]
+struct ControlDataOverrides {
+    nacp: Option<ApplicationControlProperty>,
+    icon_data: Option<Vec<u8>>
+}
+
+static mut G_OVERRIDES: BTreeMap<ApplicationId, ControlDataOverrides> = BTreeMap::new();
+static mut G_OVERRIDES_LOCK: sync::Mutex = sync::Mutex::new(false);
+
+fn read_overrides(app_id: ApplicationId) -> ControlDataOverrides {
+    ControlDataOverrides {
+        nacp: read_override_nacp(app_id),
+        icon_data: read_override_icon(app_id)
+    }
+}
+
+fn get_overrides(app_id: ApplicationId) -> ControlDataOverrides {
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_OVERRIDES_LOCK);
+        if let Some(overrides) = G_OVERRIDES.get(&app_id) {
+            return overrides.clone();
+        }
+    }
+
+    let overrides = read_overrides(app_id);
+    unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_OVERRIDES_LOCK);
+        G_OVERRIDES.insert(app_id, overrides.clone());
+    }
+    overrides
+}
+
+// Returns whether any cached title's overrides changed since they were last read
+
+pub fn reload_overrides() -> bool {
+    let app_ids: Vec<ApplicationId> = unsafe {
+        let _lock = sync::ScopedLock::new(&mut G_OVERRIDES_LOCK);
+        G_OVERRIDES.keys().copied().collect()
+    };
+
+    let mut changed = false;
+    for app_id in app_ids {
+        let overrides = read_overrides(app_id);
+        let old_overrides = unsafe {
+            let _lock = sync::ScopedLock::new(&mut G_OVERRIDES_LOCK);
+            G_OVERRIDES.insert(app_id, overrides.clone())
+        };
+        if old_overrides.as_ref() != Some(&overrides) {
+            // Relabeled titles sort differently
+            order::invalidate_title_key(app_id);
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn merge_title(title: &mut ApplicationTitle, override_title: &ApplicationTitle) {
+    if override_title.name[0] != 0 {
+        title.name = override_title.name;
+    }
+    if override_title.author[0] != 0 {
+        title.author = override_title.author;
+    }
+}
+
+fn merge_nacp(nacp: &mut ApplicationControlProperty, override_nacp: &ApplicationControlProperty) {
+    for (title, override_title) in nacp.titles.iter_mut().zip(override_nacp.titles.iter()) {
+        merge_title(title, override_title);
+    }
+}
+
+// Only the NACP part, for whoever just needs the (possibly relabeled) titles
+
+pub fn apply_nacp_override(app_id: ApplicationId, nacp: &mut ApplicationControlProperty) {
+    if let Some(override_nacp) = get_overrides(app_id).nacp {
+        merge_nacp(nacp, &override_nacp);
+    }
+}
+
+// Applies any overrides to real control data (the NACP followed by the icon) already written to the buffer, returning its new size
+
+pub fn apply_control_data_overrides(app_id: ApplicationId, out_buf: sf::OutMapAliasBuffer, size: usize) -> Result<usize> {
+    let nacp_size = core::mem::size_of::<ApplicationControlProperty>();
+    if (size < nacp_size) || (out_buf.size < nacp_size) {
+        return Ok(size);
+    }
+
+    let ControlDataOverrides { nacp: override_nacp, icon_data: mut override_icon_data } = get_overrides(app_id);
+
+    // Everything is checked before writing anything: an icon not fitting in the caller's buffer is ignored, leaving the real one
+    if let Some(icon_data) = override_icon_data.as_ref() {
+        if nacp_size + icon_data.len() > out_buf.size {
+            diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Ignoring icon override of {}, {:#X} bytes don't fit in a {:#X} byte buffer\n", app_id, icon_data.len(), out_buf.size - nacp_size);
+            override_icon_data = None;
+        }
+    }
+    if override_nacp.is_none() && override_icon_data.is_none() {
+        return Ok(size);
+    }
+
+    let mut out_writer = buf::OutBufferWriter::new(out_buf.clone());
+    if let Some(override_nacp) = override_nacp {
+        let mut nacp = unsafe {
+            core::ptr::read_unaligned(out_buf.buf as *const ApplicationControlProperty)
+        };
+        merge_nacp(&mut nacp, &override_nacp);
+        out_writer.write(&nacp)?;
+    }
+
+    let new_size = match override_icon_data {
+        Some(icon_data) => {
+            out_writer.seek(nacp_size)?;
+            out_writer.write_bytes(&icon_data)?;
+            nacp_size + icon_data.len()
+        },
+        None => size
+    };
+
+    diag_log!(logger::FilteredLogger { log::LogSeverity::Trace, true } => "[nashe] Applied control data overrides to {}\n", app_id);
+    Ok(new_size)
+}
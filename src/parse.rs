@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+// Config value parsers, free of nx so that the host can test them
+
+// Numbers are decimal, or hex with a "0x" prefix
+
+pub fn parse_u64(value: &str) -> Option<u64> {
+    let value = value.trim();
+    match value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")) {
+        Some(hex_value) => parse_hex_digits(hex_value),
+        None if !value.is_empty() && value.chars().all(|ch| ch.is_ascii_digit()) => value.parse().ok(),
+        None => None
+    }
+}
+
+// Out of range values are rejected instead of truncated
+
+pub fn parse_u32(value: &str) -> Option<u32> {
+    parse_u64(value).and_then(|value| u32::try_from(value).ok())
+}
+
+pub fn parse_usize(value: &str) -> Option<usize> {
+    parse_u64(value).and_then(|value| usize::try_from(value).ok())
+}
+
+fn parse_hex_digits(digits: &str) -> Option<u64> {
+    if digits.is_empty() || (digits.len() > 16) || !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(digits, 16).ok()
+}
+
+// Program/application IDs are always hex, with or without the "0x" prefix ("0x0100000000010000" or "0100000000010000")
+
+#[inline]
+pub fn strip_hex_prefix(value: &str) -> &str {
+    value.strip_prefix("0x").or_else(|| value.strip_prefix("0X")).unwrap_or(value)
+}
+
+pub fn parse_id(value: &str) -> Option<u64> {
+    parse_hex_digits(strip_hex_prefix(value.trim()))
+}
+
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None
+    }
+}
+
+// Comma-separated lists, where a single invalid item invalidates the whole list
+
+pub fn parse_list<T>(value: &str, parse_fn: fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|item| item.trim()).filter(|item| !item.is_empty()).map(parse_fn).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_u64("1234"), Some(1234));
+        assert_eq!(parse_u64(" 0x1F "), Some(0x1F));
+        assert_eq!(parse_u64("0X1f"), Some(0x1F));
+        assert_eq!(parse_u64("0x"), None);
+        assert_eq!(parse_u64("+5"), None);
+        assert_eq!(parse_u64("12a"), None);
+        assert_eq!(parse_u64(""), None);
+        assert_eq!(parse_u64("0x10000000000000000"), None);
+    }
+
+    #[test]
+    fn ranged_numbers() {
+        assert_eq!(parse_u32("4294967295"), Some(u32::MAX));
+        assert_eq!(parse_u32("4294967296"), None);
+        assert_eq!(parse_u32("0x100000000"), None);
+        assert_eq!(parse_usize("0x100000"), Some(0x100000));
+    }
+
+    #[test]
+    fn ids_are_always_hex() {
+        assert_eq!(parse_id("0x0100000000010000"), Some(0x0100000000010000));
+        assert_eq!(parse_id("0100000000010000"), Some(0x0100000000010000));
+        assert_eq!(parse_id("1000"), Some(0x1000));
+        assert_eq!(parse_id("0x01000000000100000"), None);
+        assert_eq!(parse_id("0x0100*"), None);
+        assert_eq!(parse_id(""), None);
+    }
+
+    #[test]
+    fn bools_and_lists() {
+        assert_eq!(parse_bool("Yes"), Some(true));
+        assert_eq!(parse_bool("off"), Some(false));
+        assert_eq!(parse_bool("maybe"), None);
+        assert_eq!(parse_list("1, 0x2,,3", parse_u64), Some(vec![1, 2, 3]));
+        assert_eq!(parse_list("1, two", parse_u64), None);
+        assert_eq!(parse_list("", parse_u64), Some(vec![]));
+    }
+}
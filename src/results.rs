@@ -0,0 +1,132 @@
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+// Result codes returned by nashe itself, under a module number no official service uses
+
+pub const RESULT_MODULE: u32 = 352;
+
+const MODULE_BITS: u32 = 9;
+const DESCRIPTION_BITS: u32 = 13;
+
+#[inline]
+pub const fn get_module(rc: u32) -> u32 {
+    rc & ((1 << MODULE_BITS) - 1)
+}
+
+#[inline]
+pub const fn get_description(rc: u32) -> u32 {
+    (rc >> MODULE_BITS) & ((1 << DESCRIPTION_BITS) - 1)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ResultDescription {
+    ExtraApplicationNotFound = 1,
+    InvalidNroMagic = 2,
+    InvalidAssetSection = 3,
+    InvalidNacpSize = 4,
+    IdMapParseError = 5,
+    ApplicationIdsExhausted = 6,
+    ConfigParseError = 7,
+    BufferTooSmall = 8,
+    HostApplicationNotConfigured = 9,
+    OutOfMemory = 10,
+    AsyncNotFinished = 11,
+    AsyncCanceled = 12,
+    ApplicationHidden = 13
+}
+
+impl ResultDescription {
+    pub const fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            1 => Some(ResultDescription::ExtraApplicationNotFound),
+            2 => Some(ResultDescription::InvalidNroMagic),
+            3 => Some(ResultDescription::InvalidAssetSection),
+            4 => Some(ResultDescription::InvalidNacpSize),
+            5 => Some(ResultDescription::IdMapParseError),
+            6 => Some(ResultDescription::ApplicationIdsExhausted),
+            7 => Some(ResultDescription::ConfigParseError),
+            8 => Some(ResultDescription::BufferTooSmall),
+            9 => Some(ResultDescription::HostApplicationNotConfigured),
+            10 => Some(ResultDescription::OutOfMemory),
+            11 => Some(ResultDescription::AsyncNotFinished),
+            12 => Some(ResultDescription::AsyncCanceled),
+            13 => Some(ResultDescription::ApplicationHidden),
+            _ => None
+        }
+    }
+
+    pub const fn get_name(&self) -> &'static str {
+        match self {
+            ResultDescription::ExtraApplicationNotFound => "ExtraApplicationNotFound",
+            ResultDescription::InvalidNroMagic => "InvalidNroMagic",
+            ResultDescription::InvalidAssetSection => "InvalidAssetSection",
+            ResultDescription::InvalidNacpSize => "InvalidNacpSize",
+            ResultDescription::IdMapParseError => "IdMapParseError",
+            ResultDescription::ApplicationIdsExhausted => "ApplicationIdsExhausted",
+            ResultDescription::ConfigParseError => "ConfigParseError",
+            ResultDescription::BufferTooSmall => "BufferTooSmall",
+            ResultDescription::HostApplicationNotConfigured => "HostApplicationNotConfigured",
+            ResultDescription::OutOfMemory => "OutOfMemory",
+            ResultDescription::AsyncNotFinished => "AsyncNotFinished",
+            ResultDescription::AsyncCanceled => "AsyncCanceled",
+            ResultDescription::ApplicationHidden => "ApplicationHidden"
+        }
+    }
+
+    #[inline]
+    pub const fn get_value(&self) -> u32 {
+        RESULT_MODULE | ((*self as u32) << MODULE_BITS)
+    }
+}
+
+impl Display for ResultDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+pub const fn lookup_result(rc: u32) -> Option<ResultDescription> {
+    if get_module(rc) == RESULT_MODULE {
+        ResultDescription::from_raw(get_description(rc))
+    }
+    else {
+        None
+    }
+}
+
+// Formats any raw result value as "2XXX-YYYY", naming it if it's one of ours
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ResultDisplay(pub u32);
+
+impl Display for ResultDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:04}-{:04}", 2000 + get_module(self.0), get_description(self.0))?;
+        if let Some(desc) = lookup_result(self.0) {
+            write!(f, " ({})", desc)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_round_trip() {
+        for raw in 1..=13 {
+            let desc = ResultDescription::from_raw(raw).unwrap();
+            assert_eq!(get_module(desc.get_value()), RESULT_MODULE);
+            assert_eq!(lookup_result(desc.get_value()), Some(desc));
+        }
+        assert_eq!(ResultDescription::from_raw(0), None);
+        assert_eq!(lookup_result(ResultDescription::BufferTooSmall.get_value() & !0x1FF), None);
+    }
+
+    #[test]
+    fn display_names_own_results() {
+        assert_eq!(format!("{}", ResultDisplay(ResultDescription::BufferTooSmall.get_value())), "2352-0008 (BufferTooSmall)");
+        assert_eq!(format!("{}", ResultDisplay(0x1F4)), "2500-0000");
+    }
+}
@@ -0,0 +1,287 @@
+use alloc::vec::Vec;
+use alloc::string::String;
+use nx::result::*;
+use nashe::results::ResultDisplay;
+use nx::ipc::sf;
+use nx::mem::Shared;
+use nx::util::CString;
+use nx::arm;
+use nx::fs;
+use nx::diag::log;
+use crate::logger;
+use crate::config;
+use crate::ns::{ApplicationContentMetaStatus, ApplicationControlSource, ApplicationId, ApplicationLaunchInfo, ApplicationOccupiedSize, ApplicationRecord, CallerInfo, ContentMetaType, GameCardCompatibilityType, GetterServiceKind, ProgramId, ReceiveApplicationProgress, SaveDataSpaceId, SendApplicationProgress, StorageId, Uid};
+
+pub use nashe::trace_format::{TRACE_MAGIC, TRACE_VERSION, TraceInterface, TraceParamKind, TraceFileHeader, TraceRecordHeader, TraceParamHeader};
+use nashe::trace_format::{push_bytes, push_param};
+
+pub const TRACE_DIRECTORY: &str = "sdmc:/nashe/logs";
+
+// Buffers might be huge, only keep their beginning
+
+const MAX_IN_BUFFER_DATA_SIZE: usize = 0x200;
+const MAX_OUT_BUFFER_DATA_SIZE: usize = 0x1000;
+
+// Smallest configurable file size, so that any record (a few values plus a couple of kept buffers) fits in a fresh file
+pub const MIN_MAX_FILE_SIZE: usize = 0x4000;
+
+// Both command inputs and outputs are recorded through this, one parameter per value
+// Only padding-free types are recorded as raw bytes, tuples are split into their elements
+
+pub trait TraceParam {
+    fn write_param(&self, data: &mut Vec<u8>);
+
+    // Nothing by default, for things which are never outputs (and for "no output")
+    fn write_out_param(&self, _data: &mut Vec<u8>) {}
+
+    fn get_out_buffer(&self) -> Option<sf::OutMapAliasBuffer> {
+        None
+    }
+}
+
+fn push_value<T: Copy>(t: &T, kind: TraceParamKind, data: &mut Vec<u8>) {
+    let mut value_data: Vec<u8> = Vec::new();
+    push_bytes(t, &mut value_data);
+    push_param(kind, value_data.len(), &value_data, data);
+}
+
+macro_rules! impl_value_trace_param {
+    ($($t:ty),*) => {
+        $(
+            impl TraceParam for $t {
+                fn write_param(&self, data: &mut Vec<u8>) {
+                    push_value(self, TraceParamKind::Value, data);
+                }
+
+                fn write_out_param(&self, data: &mut Vec<u8>) {
+                    push_value(self, TraceParamKind::OutValue, data);
+                }
+            }
+        )*
+    };
+}
+
+impl_value_trace_param!(u8, u16, u32, u64, i32, usize, bool, [u8; 0x20], ResultCode, ApplicationId, ProgramId, StorageId, ContentMetaType, ApplicationControlSource, SaveDataSpaceId, Uid, ApplicationLaunchInfo, ApplicationRecord, ApplicationContentMetaStatus, ApplicationOccupiedSize, GameCardCompatibilityType, ReceiveApplicationProgress, SendApplicationProgress);
+
+impl<const S: usize> TraceParam for CString<S> {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        push_value(self, TraceParamKind::Value, data);
+    }
+
+    fn write_out_param(&self, data: &mut Vec<u8>) {
+        push_value(self, TraceParamKind::OutValue, data);
+    }
+}
+
+impl TraceParam for () {
+    fn write_param(&self, _data: &mut Vec<u8>) {}
+}
+
+impl<A: TraceParam, B: TraceParam> TraceParam for (A, B) {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        self.0.write_param(data);
+        self.1.write_param(data);
+    }
+
+    fn write_out_param(&self, data: &mut Vec<u8>) {
+        self.0.write_out_param(data);
+        self.1.write_out_param(data);
+    }
+}
+
+impl TraceParam for sf::InMapAliasBuffer {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        let buf_data = self.get_slice::<u8>();
+        let kept_size = core::cmp::min(buf_data.len(), MAX_IN_BUFFER_DATA_SIZE);
+        push_param(TraceParamKind::InBuffer, self.size, &buf_data[..kept_size], data);
+    }
+}
+
+impl TraceParam for sf::OutMapAliasBuffer {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        push_param(TraceParamKind::OutBuffer, self.size, &[], data);
+    }
+
+    fn get_out_buffer(&self) -> Option<sf::OutMapAliasBuffer> {
+        Some(self.clone())
+    }
+}
+
+impl TraceParam for sf::CopyHandle {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        push_value(&self.handle, TraceParamKind::Handle, data);
+    }
+
+    fn write_out_param(&self, data: &mut Vec<u8>) {
+        push_value(&self.handle, TraceParamKind::OutHandle, data);
+    }
+}
+
+// Objects are just noted, never dumped (and ns never takes them as inputs)
+
+impl TraceParam for Shared<dyn sf::IObject> {
+    fn write_param(&self, data: &mut Vec<u8>) {
+        self.write_out_param(data);
+    }
+
+    fn write_out_param(&self, data: &mut Vec<u8>) {
+        push_param(TraceParamKind::OutObject, 0, &[], data);
+    }
+}
+
+// Inputs are recorded before running the command (which might consume them), and nothing at all is done with tracing disabled
+
+macro_rules! trace_command {
+    ($kind:expr, $caller:expr, $intf:expr, $cmd_id:expr, ($($in_param:ident),*) => $body:block) => {{
+        let trace_inputs = match $crate::trace::is_enabled() {
+            true => {
+                let mut trace_in_data: alloc::vec::Vec<u8> = alloc::vec::Vec::new();
+                let mut trace_out_bufs: alloc::vec::Vec<nx::ipc::sf::OutMapAliasBuffer> = alloc::vec::Vec::new();
+                $(
+                    $crate::trace::TraceParam::write_param(&$in_param, &mut trace_in_data);
+                    trace_out_bufs.extend($crate::trace::TraceParam::get_out_buffer(&$in_param));
+                )*
+                Some((trace_in_data, trace_out_bufs))
+            },
+            false => None
+        };
+        let trace_rc = (|| -> nx::result::Result<_> $body)();
+        if let Some((trace_in_data, trace_out_bufs)) = trace_inputs {
+            let trace_caller: $crate::ns::CallerInfo = $caller;
+            $crate::trace::record_command($kind, trace_caller, $intf, $cmd_id, &trace_in_data, &trace_out_bufs, &trace_rc);
+        }
+        trace_rc
+    }};
+}
+
+struct TraceLog {
+    file: fs::File,
+    file_size: usize
+}
+
+static mut G_TRACE_LOG: Option<TraceLog> = None;
+
+fn make_trace_path(index: u32) -> String {
+    format!("{}/trace_{}.bin", TRACE_DIRECTORY, index)
+}
+
+fn open_trace_log() -> Result<TraceLog> {
+    let _ = fs::create_directory(String::from("sdmc:/nashe"));
+    let _ = fs::create_directory(String::from(TRACE_DIRECTORY));
+
+    let mut file = fs::open_file(make_trace_path(0), fs::FileOpenOption::Create() | fs::FileOpenOption::Write() | fs::FileOpenOption::Append())?;
+    let mut file_size = file.get_size()?;
+    if file_size == 0 {
+        let header = TraceFileHeader {
+            magic: TRACE_MAGIC,
+            version: TRACE_VERSION
+        };
+        file.write_val(&header)?;
+        file_size = core::mem::size_of::<TraceFileHeader>();
+    }
+
+    Ok(TraceLog { file, file_size })
+}
+
+fn rotate_trace_files() -> Result<()> {
+    // trace_0.bin is always the current file, older ones get shifted up until the file count cap
+    let max_files = config::get().trace_max_files;
+    let _ = fs::remove_file(make_trace_path(max_files - 1));
+    for index in (0..max_files - 1).rev() {
+        let _ = fs::rename_file(make_trace_path(index), make_trace_path(index + 1));
+    }
+    Ok(())
+}
+
+pub fn initialize() {
+    if config::get().trace_enabled {
+        match open_trace_log() {
+            Ok(trace_log) => unsafe {
+                G_TRACE_LOG = Some(trace_log);
+            },
+            Err(rc) => diag_log!(logger::FilteredLogger { log::LogSeverity::Warn, true } => "[nashe] Unable to open trace log, tracing is disabled: {}\n", ResultDisplay(rc.get_value()))
+        };
+    }
+}
+
+#[inline]
+pub fn is_enabled() -> bool {
+    unsafe {
+        G_TRACE_LOG.is_some()
+    }
+}
+
+fn write_record(record: &[u8]) -> Result<()> {
+    let trace_log = match unsafe { G_TRACE_LOG.as_mut() } {
+        Some(trace_log) => trace_log,
+        None => return Ok(())
+    };
+
+    // A record always goes into a fresh file, even if it doesn't fit there either
+    let trace_log = if (trace_log.file_size + record.len()) > config::get().trace_max_file_size {
+        unsafe {
+            G_TRACE_LOG = None;
+        }
+        rotate_trace_files()?;
+
+        let new_trace_log = open_trace_log()?;
+        unsafe {
+            G_TRACE_LOG = Some(new_trace_log);
+            G_TRACE_LOG.as_mut().unwrap()
+        }
+    }
+    else {
+        trace_log
+    };
+
+    trace_log.file.write_array(record)?;
+    trace_log.file_size += record.len();
+    Ok(())
+}
+
+pub fn record_command<T: TraceParam>(kind: GetterServiceKind, caller: CallerInfo, intf: TraceInterface, command_id: u32, in_data: &[u8], out_bufs: &[sf::OutMapAliasBuffer], rc: &Result<T>) {
+    if !is_enabled() {
+        return;
+    }
+
+    let mut param_data = in_data.to_vec();
+    let mut out_size: usize = 0;
+    if let Ok(out) = rc {
+        out.write_out_param(&mut param_data);
+        out_size = param_data.len() - in_data.len();
+
+        for out_buf in out_bufs {
+            let buf_data = out_buf.get_slice::<u8>();
+            let kept_size = core::cmp::min(buf_data.len(), MAX_OUT_BUFFER_DATA_SIZE);
+            push_param(TraceParamKind::OutBufferData, out_buf.size, &buf_data[..kept_size], &mut param_data);
+        }
+    }
+
+    let header = TraceRecordHeader {
+        timestamp: arm::get_system_tick(),
+        program_id: caller.program_id.0,
+        process_id: caller.process_id,
+        interface: intf as u8,
+        service_kind: kind as u8,
+        pad: [0; 0x2],
+        command_id,
+        rc: match rc {
+            Ok(_) => ResultSuccess::make().get_value(),
+            Err(rc) => rc.get_value()
+        },
+        param_data_size: param_data.len() as u32,
+        out_size: out_size as u32,
+        pad_2: [0; 0x4]
+    };
+
+    let mut record: Vec<u8> = Vec::new();
+    push_bytes(&header, &mut record);
+    record.extend_from_slice(&param_data);
+
+    if let Err(rc) = write_record(&record) {
+        diag_log!(logger::FilteredLogger { log::LogSeverity::Error, true } => "[nashe] Unable to write trace record, tracing is disabled: {}\n", ResultDisplay(rc.get_value()));
+        unsafe {
+            G_TRACE_LOG = None;
+        }
+    }
+}
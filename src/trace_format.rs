@@ -0,0 +1,122 @@
+// Trace files are a TraceFileHeader followed by records, each one being a TraceRecordHeader followed by its parameters
+// Every parameter is a TraceParamHeader followed by its data (if any):
+// - first the command inputs, in command order (output buffers only have their size recorded here)
+// - then, if the command succeeded, its outputs (one parameter each, in command order) and the contents of its output buffers (in command order)
+// Output handles only have their value recorded, and output objects only their presence
+
+use alloc::vec::Vec;
+
+pub const TRACE_MAGIC: u32 = u32::from_le_bytes(*b"NSTR");
+pub const TRACE_VERSION: u32 = 2;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum TraceInterface {
+    ServiceGetter = 0,
+    ReadOnlyApplicationControlData = 1,
+    ApplicationManager = 2
+}
+
+impl TraceInterface {
+    pub const fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            0 => Some(TraceInterface::ServiceGetter),
+            1 => Some(TraceInterface::ReadOnlyApplicationControlData),
+            2 => Some(TraceInterface::ApplicationManager),
+            _ => None
+        }
+    }
+
+    pub const fn get_name(&self) -> &'static str {
+        match self {
+            TraceInterface::ServiceGetter => "IServiceGetterInterface",
+            TraceInterface::ReadOnlyApplicationControlData => "IReadOnlyApplicationControlDataInterface",
+            TraceInterface::ApplicationManager => "IApplicationManagerInterface"
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum TraceParamKind {
+    Value = 0,
+    InBuffer = 1,
+    OutBuffer = 2,
+    Handle = 3,
+    OutValue = 4,
+    OutBufferData = 5,
+    OutHandle = 6,
+    OutObject = 7
+}
+
+impl TraceParamKind {
+    pub const fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            0 => Some(TraceParamKind::Value),
+            1 => Some(TraceParamKind::InBuffer),
+            2 => Some(TraceParamKind::OutBuffer),
+            3 => Some(TraceParamKind::Handle),
+            4 => Some(TraceParamKind::OutValue),
+            5 => Some(TraceParamKind::OutBufferData),
+            6 => Some(TraceParamKind::OutHandle),
+            7 => Some(TraceParamKind::OutObject),
+            _ => None
+        }
+    }
+}
+
+// Same order as nashe's GetterServiceKind
+
+pub const TRACE_SERVICE_NAMES: [&str; 6] = ["ns:am2", "ns:ec", "ns:rid", "ns:rt", "ns:web", "ns:ro"];
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct TraceFileHeader {
+    pub magic: u32,
+    pub version: u32
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct TraceRecordHeader {
+    pub timestamp: u64,
+    pub program_id: u64,
+    pub process_id: u64,
+    pub interface: u8,
+    pub service_kind: u8,
+    pub pad: [u8; 0x2],
+    pub command_id: u32,
+    pub rc: u32,
+    pub param_data_size: u32,
+    pub out_size: u32,
+    pub pad_2: [u8; 0x4]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct TraceParamHeader {
+    pub kind: u8,
+    pub pad: [u8; 0x3],
+    pub size: u32,
+    pub data_size: u32
+}
+
+// Shared by nashe itself and the decoder tests, so that both agree on the byte layout
+
+pub fn push_bytes<T: Copy>(t: &T, data: &mut Vec<u8>) {
+    let t_bytes = unsafe {
+        core::slice::from_raw_parts(t as *const T as *const u8, core::mem::size_of::<T>())
+    };
+    data.extend_from_slice(t_bytes);
+}
+
+pub fn push_param(kind: TraceParamKind, size: usize, param_data: &[u8], data: &mut Vec<u8>) {
+    let header = TraceParamHeader {
+        kind: kind as u8,
+        pad: [0; 0x3],
+        size: size as u32,
+        data_size: param_data.len() as u32
+    };
+    push_bytes(&header, data);
+    data.extend_from_slice(param_data);
+}
@@ -0,0 +1,412 @@
+use core::fmt::{Display, Debug, Formatter, Result as FmtResult};
+
+// Note: ncm type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[repr(C)]
+pub struct ProgramId(pub u64);
+
+impl Display for ProgramId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:#018X}", self.0)
+    }
+}
+
+impl Debug for ProgramId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:#018X}", self.0)
+    }
+}
+
+// Note: ncm type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[repr(C)]
+pub struct ApplicationId(pub u64);
+
+impl Display for ApplicationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:#018X}", self.0)
+    }
+}
+
+impl Debug for ApplicationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:#018X}", self.0)
+    }
+}
+
+// Note: ncm type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum StorageId {
+    None = 0,
+    Host = 1,
+    GameCard = 2,
+    BuiltInSystem = 3,
+    BuiltInUser = 4,
+    SdCard = 5,
+    Any = 6
+}
+
+// Manual Default impls: the sysmodule's nightly still gates #[default] on enums (derive_default_enum), unlike the host toolchain running the layout tests
+
+#[allow(clippy::derivable_impls)]
+impl Default for StorageId {
+    fn default() -> Self {
+        StorageId::None
+    }
+}
+
+impl StorageId {
+    pub const fn from_raw(raw: u8) -> Option<Self> {
+        match raw {
+            0 => Some(StorageId::None),
+            1 => Some(StorageId::Host),
+            2 => Some(StorageId::GameCard),
+            3 => Some(StorageId::BuiltInSystem),
+            4 => Some(StorageId::BuiltInUser),
+            5 => Some(StorageId::SdCard),
+            6 => Some(StorageId::Any),
+            _ => None
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationRecord {
+    pub app_id: ApplicationId,
+    pub unk_type: u8,
+    pub unk_2: u8,
+    pub unk_3: [u8; 0x6],
+    pub unk_4: u8,
+    pub unk_reserved: [u8; 0x7]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationView {
+    pub app_id: ApplicationId,
+    pub unk_1: u32,
+    pub unk_flags: u32,
+    pub unk_2: [u8; 0x10],
+    pub unk_3: u32,
+    pub unk_4: u16,
+    pub unk_reserved: [u8; 0x2],
+    pub unk_6: [u8; 0x8],
+    pub unk_7: [u8; 0x10],
+    pub unk_8: u32,
+    pub unk_9: u8,
+    pub unk_reserved_2: [u8; 0xB]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct ApplicationLaunchInfo {
+    pub app_id: ApplicationId,
+    pub version: u32,
+    pub pm_launch_flags: u32 /* */,
+    pub app_storage_id: StorageId,
+    pub update_storage_id: StorageId,
+    pub other_data: [u8; 0x2E]
+}
+
+// Note: account type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct Uid {
+    pub hi: u64,
+    pub lo: u64
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationRightsOnClient {
+    pub app_id: ApplicationId,
+    pub uid: Uid,
+    pub flags_1: u8,
+    pub flags_2: u8,
+    pub unk: [u8; 0x6]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct ApplicationTitle {
+    pub name: [u8; 0x200],
+    pub author: [u8; 0x100]
+}
+
+// Plain byte arrays instead of nx's CString (same layout), which are too big for a derived Default
+
+impl Default for ApplicationTitle {
+    fn default() -> Self {
+        Self { name: [0; 0x200], author: [0; 0x100] }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug /*, Default */)]
+#[repr(C)]
+pub struct ApplicationControlProperty {
+    pub titles: [ApplicationTitle; 16],
+    pub todo_other_stuff: [u8; 0x1000]
+}
+
+// Icons are JPEGs of at most this size
+
+pub const MAX_APPLICATION_ICON_SIZE: usize = 0x20000;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ApplicationControlSource {
+    CacheOnly,
+    Storage,
+    StorageOnly
+}
+
+// Note: ncm type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ContentMetaType {
+    Unknown = 0x0,
+    SystemProgram = 0x1,
+    SystemData = 0x2,
+    SystemUpdate = 0x3,
+    BootImagePackage = 0x4,
+    BootImagePackageSafe = 0x5,
+    Application = 0x80,
+    Patch = 0x81,
+    AddOnContent = 0x82,
+    Delta = 0x83
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for ContentMetaType {
+    fn default() -> Self {
+        ContentMetaType::Unknown
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationViewDeprecated {
+    pub app_id: ApplicationId,
+    pub unk_1: u32,
+    pub unk_flags: u32,
+    pub unk_2: [u8; 0x10],
+    pub unk_3: u32,
+    pub unk_4: u16,
+    pub unk_reserved: [u8; 0x2],
+    pub unk_7: [u8; 0x10],
+    pub unk_8: u32,
+    pub unk_9: u8,
+    pub unk_reserved_3: [u8; 0x3]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct ApplicationOccupiedSize {
+    pub unk_data: [u8; 0x80]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct ProgressForDeleteUserSaveDataAll {
+    pub data: [u8; 0x28]
+}
+
+// Note: fs type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum SaveDataSpaceId {
+    System = 0,
+    User = 1,
+    SdSystem = 2,
+    Temporary = 3,
+    SdUser = 4,
+    ProperSystem = 100,
+    SafeMode = 101
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationContentMetaStatus {
+    pub meta_type: ContentMetaType,
+    pub storage: StorageId,
+    pub unk: u8, /**/
+    pub pad: u8,
+    pub version: u32,
+    pub app_id: ApplicationId
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct DownloadTaskStatus {
+    pub data: [u8; 0x20]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct VersionListData {
+    pub data: [u8; 0x20 /**/]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct PromotionInfo {
+    pub todo_data: [u8; 0x20 /**/]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ApplicationViewWithPromotionInfo {
+    pub view: ApplicationView,
+    pub info: PromotionInfo
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct SystemDeliveryInfo {
+    pub system_delivery_protocol_version: u32,
+    pub app_delivery_protocol_version: u32,
+    pub has_exfat: bool,
+    pub pad: [u8; 0x3],
+    pub system_update_meta_version: u32,
+    pub system_update_meta_id: u64,
+    pub fw_variation_id: u8,
+    pub updatable_fw_group_id: u8,
+    pub platform_region: u8,
+    pub unused: [u8; 0xC5],
+    pub hmac_sha256_above: [u8; 0x20]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct ApplicationDeliveryInfo {
+    pub unk: [u8; 0x8],
+    pub app_id: ApplicationId,
+    pub app_version: u32,
+    pub unk_2: [u8; 0x4],
+    pub required_system_version: u32,
+    pub unk_3: [u8; 0x4],
+    pub unk_4: [u8; 0xC0],
+    pub hmac_sha256_above: [u8; 0x20]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct ReceiveApplicationProgress {
+    pub data: [u8; 0x10]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct SendApplicationProgress {
+    pub data: [u8; 0x10]
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[repr(C)]
+pub struct NoDownloadRightsErrorResolution {
+}
+
+// Note: fs type, not ns one
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum GameCardCompatibilityType {
+    Global = 0,
+    China = 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::mem::{offset_of, size_of};
+
+    // Sizes and offsets as laid out by the real ns/ncm/fs/account services
+
+    #[test]
+    fn ncm_account_layouts() {
+        assert_eq!(size_of::<ProgramId>(), 0x8);
+        assert_eq!(size_of::<ApplicationId>(), 0x8);
+        assert_eq!(size_of::<StorageId>(), 0x1);
+        assert_eq!(size_of::<ContentMetaType>(), 0x1);
+        assert_eq!(size_of::<SaveDataSpaceId>(), 0x1);
+        assert_eq!(size_of::<Uid>(), 0x10);
+    }
+
+    #[test]
+    fn application_record_layout() {
+        assert_eq!(size_of::<ApplicationRecord>(), 0x18);
+        assert_eq!(offset_of!(ApplicationRecord, unk_type), 0x8);
+        assert_eq!(offset_of!(ApplicationRecord, unk_4), 0x10);
+    }
+
+    #[test]
+    fn application_view_layouts() {
+        assert_eq!(size_of::<ApplicationView>(), 0x50);
+        assert_eq!(offset_of!(ApplicationView, unk_flags), 0xC);
+        assert_eq!(offset_of!(ApplicationView, unk_3), 0x20);
+        assert_eq!(offset_of!(ApplicationView, unk_6), 0x28);
+        assert_eq!(offset_of!(ApplicationView, unk_8), 0x40);
+
+        assert_eq!(size_of::<ApplicationViewDeprecated>(), 0x40);
+        assert_eq!(offset_of!(ApplicationViewDeprecated, unk_7), 0x28);
+        assert_eq!(offset_of!(ApplicationViewDeprecated, unk_8), 0x38);
+
+        assert_eq!(size_of::<PromotionInfo>(), 0x20);
+        assert_eq!(size_of::<ApplicationViewWithPromotionInfo>(), 0x70);
+        assert_eq!(offset_of!(ApplicationViewWithPromotionInfo, info), 0x50);
+    }
+
+    #[test]
+    fn application_control_property_layout() {
+        assert_eq!(size_of::<ApplicationTitle>(), 0x300);
+        assert_eq!(offset_of!(ApplicationTitle, author), 0x200);
+        assert_eq!(size_of::<ApplicationControlProperty>(), 0x4000);
+        assert_eq!(offset_of!(ApplicationControlProperty, todo_other_stuff), 0x3000);
+    }
+
+    #[test]
+    fn application_launch_layouts() {
+        assert_eq!(size_of::<ApplicationLaunchInfo>(), 0x40);
+        assert_eq!(offset_of!(ApplicationLaunchInfo, app_storage_id), 0x10);
+        assert_eq!(offset_of!(ApplicationLaunchInfo, update_storage_id), 0x11);
+
+        assert_eq!(size_of::<ApplicationRightsOnClient>(), 0x20);
+        assert_eq!(offset_of!(ApplicationRightsOnClient, uid), 0x8);
+        assert_eq!(offset_of!(ApplicationRightsOnClient, flags_1), 0x18);
+
+        assert_eq!(size_of::<ApplicationContentMetaStatus>(), 0x10);
+        assert_eq!(offset_of!(ApplicationContentMetaStatus, version), 0x4);
+        assert_eq!(offset_of!(ApplicationContentMetaStatus, app_id), 0x8);
+
+        assert_eq!(size_of::<ApplicationOccupiedSize>(), 0x80);
+    }
+
+    #[test]
+    fn delivery_info_layouts() {
+        assert_eq!(size_of::<SystemDeliveryInfo>(), 0x100);
+        assert_eq!(offset_of!(SystemDeliveryInfo, system_update_meta_id), 0x10);
+        assert_eq!(offset_of!(SystemDeliveryInfo, hmac_sha256_above), 0xE0);
+
+        assert_eq!(size_of::<ApplicationDeliveryInfo>(), 0x100);
+        assert_eq!(offset_of!(ApplicationDeliveryInfo, app_id), 0x8);
+        assert_eq!(offset_of!(ApplicationDeliveryInfo, required_system_version), 0x18);
+        assert_eq!(offset_of!(ApplicationDeliveryInfo, hmac_sha256_above), 0xE0);
+    }
+
+    #[test]
+    fn progress_layouts() {
+        assert_eq!(size_of::<ProgressForDeleteUserSaveDataAll>(), 0x28);
+        assert_eq!(size_of::<DownloadTaskStatus>(), 0x20);
+        assert_eq!(size_of::<VersionListData>(), 0x20);
+        assert_eq!(size_of::<ReceiveApplicationProgress>(), 0x10);
+        assert_eq!(size_of::<SendApplicationProgress>(), 0x10);
+    }
+}